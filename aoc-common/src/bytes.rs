@@ -0,0 +1,194 @@
+use std::marker::PhantomData;
+
+use crate::{error::ErrorKind, CheckedInt, SolveError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<T> {
+    Number(T),
+    EndOfLine,
+}
+
+/// Whitespace separated integers straight out of a byte buffer, with no
+/// allocation per line or per token. Accepts exactly what `str::parse` does
+/// (an optional sign then digits) and produces the same errors, with the byte
+/// offset of the bad token added. Only ASCII whitespace separates tokens.
+///
+/// Lines end at `\n`, like `BufRead::lines`: a trailing `\n` does not start
+/// another line, and a final line without one still counts.
+pub struct NumberScanner<'a, T> {
+    input: &'a [u8],
+    pos: usize,
+    line: usize,
+    line_start: usize,
+    _values: PhantomData<T>,
+}
+
+impl<'a, T: CheckedInt> NumberScanner<'a, T> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            pos: 0,
+            line: 1,
+            line_start: 0,
+            _values: PhantomData,
+        }
+    }
+
+    /// The 1-based number of the line the scanner is on.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Reads the rest of the current line into `values` (cleared first) and
+    /// returns that line's number, or `None` at the end of the input.
+    pub fn read_line(&mut self, values: &mut Vec<T>) -> Option<Result<usize, SolveError>> {
+        values.clear();
+        let line = self.line;
+        loop {
+            match self.next()? {
+                Ok(Token::Number(n)) => values.push(n),
+                Ok(Token::EndOfLine) => return Some(Ok(line)),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    /// Reads a line that must hold exactly two numbers, failing like
+    /// `parse_into_number_pair` otherwise.
+    pub fn read_pair(&mut self) -> Option<Result<(T, T), SolveError>> {
+        let (line, line_start) = (self.line, self.line_start);
+        let mut values = [T::default(); 2];
+        let mut found = 0;
+        loop {
+            match self.next()? {
+                Ok(Token::Number(n)) => {
+                    if let Some(slot) = values.get_mut(found) {
+                        *slot = n;
+                    }
+                    found += 1;
+                }
+                Ok(Token::EndOfLine) if found == 2 => return Some(Ok((values[0], values[1]))),
+                Ok(Token::EndOfLine) => {
+                    let text = self.line_text(line_start);
+                    let trimmed = text.trim();
+                    let offset = line_start + text.find(trimmed).unwrap_or(0);
+                    return Some(Err(SolveError::new(ErrorKind::WrongNumValuesToUnpack {
+                        expected: 2,
+                        found,
+                    })
+                    .at(line, 1, trimmed)
+                    .at_byte(offset)));
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    /// Skips the rest of the current line, so that reading can go on after a
+    /// bad number part way through it.
+    pub fn skip_line(&mut self) {
+        match self.input[self.pos..].iter().position(|&b| b == b'\n') {
+            Some(newline) => self.pos += newline + 1,
+            None => self.pos = self.input.len(),
+        }
+        self.line += 1;
+        self.line_start = self.pos;
+    }
+
+    fn line_text(&self, line_start: usize) -> String {
+        let end = self.pos.min(self.input.len());
+        let line = &self.input[line_start..end];
+        let line = line.strip_suffix(b"\n").unwrap_or(line);
+        String::from_utf8_lossy(line).into_owned()
+    }
+
+    /// Parses a token the fast path rejected with `str::parse`, so that both
+    /// what is accepted and the error reported match it exactly.
+    fn parse_slowly(&self, start: usize) -> Result<T, SolveError> {
+        let token = &self.input[start..self.pos];
+        let kind = match std::str::from_utf8(token).map(str::parse::<T>) {
+            Ok(Ok(n)) => return Ok(n),
+            Ok(Err(e)) => ErrorKind::NotParseableAsNumber(e),
+            Err(_) => ErrorKind::Parse("token is not valid UTF-8".to_string()),
+        };
+        let column = start - self.line_start + 1;
+        Err(SolveError::new(kind)
+            .at(self.line, column, String::from_utf8_lossy(token))
+            .at_byte(start))
+    }
+}
+
+impl<T: CheckedInt> Iterator for NumberScanner<'_, T> {
+    type Item = Result<Token<T>, SolveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(&byte) = self.input.get(self.pos) else {
+                if self.line_start < self.input.len() {
+                    self.line_start = self.input.len();
+                    self.line += 1;
+                    return Some(Ok(Token::EndOfLine));
+                }
+                return None;
+            };
+
+            if byte == b'\n' {
+                self.pos += 1;
+                self.line += 1;
+                self.line_start = self.pos;
+                return Some(Ok(Token::EndOfLine));
+            }
+            if is_separator(byte) {
+                self.pos += 1;
+                continue;
+            }
+
+            let start = self.pos;
+            while self.input.get(self.pos).is_some_and(|&b| !is_separator(b)) {
+                self.pos += 1;
+            }
+            return Some(match parse_number(&self.input[start..self.pos]) {
+                Some(n) => Ok(Token::Number(n)),
+                None => self.parse_slowly(start).map(Token::Number),
+            });
+        }
+    }
+}
+
+/// The whitespace `str::split_whitespace` recognises, restricted to ASCII.
+fn is_separator(byte: u8) -> bool {
+    byte.is_ascii_whitespace() || byte == 0x0b
+}
+
+fn parse_number<T: CheckedInt>(token: &[u8]) -> Option<T> {
+    let (negative, digits) = match token {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        _ => (false, token),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    let ten = T::from_count(10)?;
+    let mut value = T::default();
+    for &digit in digits {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        let digit = T::from_count(usize::from(digit - b'0'))?;
+        value = value.checked_mul(ten)?;
+        // Negative numbers are built downwards so that `MIN` is reachable, and
+        // unsigned types fail here for anything but "-0"...
+        value = if negative {
+            value.checked_sub(digit)?
+        } else {
+            value.checked_add(digit)?
+        };
+    }
+    // ...which `str::parse` also rejects for them.
+    if negative && T::default().checked_sub(T::from_count(1)?).is_none() {
+        return None;
+    }
+    Some(value)
+}
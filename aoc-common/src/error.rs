@@ -0,0 +1,171 @@
+use std::{fmt, num::ParseIntError};
+
+pub struct SolveError {
+    kind: ErrorKind,
+    location: Option<Location>,
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    Io(std::io::Error),
+    NotParseableAsNumber(ParseIntError),
+    WrongNumValuesToUnpack { expected: usize, found: usize },
+    Parse(String),
+    Overflow(&'static str),
+}
+
+/// Where in the input an error was found. `line` and `column` are 1-based and
+/// `snippet` is the offending token (or the whole line when no single token is
+/// to blame). `offset` is the 0-based byte offset of the snippet, when the
+/// parser knows it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub offset: Option<usize>,
+}
+
+impl SolveError {
+    pub fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            location: None,
+        }
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse(message.into()))
+    }
+
+    pub fn overflow(computing: &'static str) -> Self {
+        Self::new(ErrorKind::Overflow(computing))
+    }
+
+    pub fn at(mut self, line: usize, column: usize, snippet: impl Into<String>) -> Self {
+        let file = self.location.take().and_then(|l| l.file);
+        self.location = Some(Location {
+            file,
+            line,
+            column,
+            snippet: snippet.into(),
+            offset: None,
+        });
+        self
+    }
+
+    /// Records the byte offset of an error already placed with `at`.
+    pub fn at_byte(mut self, offset: usize) -> Self {
+        if let Some(location) = &mut self.location {
+            location.offset = Some(offset);
+        }
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        match &mut self.location {
+            Some(location) => location.file = Some(file.into()),
+            None => {
+                self.location = Some(Location {
+                    file: Some(file.into()),
+                    line: 0,
+                    column: 0,
+                    snippet: String::new(),
+                    offset: None,
+                })
+            }
+        }
+        self
+    }
+
+    /// Moves the error down by `lines` lines and `bytes` bytes, for errors
+    /// found while parsing a slice that starts partway through the input.
+    pub fn after(mut self, lines: usize, bytes: usize) -> Self {
+        if let Some(location) = &mut self.location {
+            if location.line > 0 {
+                location.line += lines;
+            }
+            if let Some(offset) = &mut location.offset {
+                *offset += bytes;
+            }
+        }
+        self
+    }
+
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+}
+
+impl From<std::io::Error> for SolveError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new(ErrorKind::Io(e))
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Io(e) => write!(f, "I/O error: {}", e),
+            ErrorKind::NotParseableAsNumber(e) => write!(f, "not parseable as a number: {}", e),
+            ErrorKind::WrongNumValuesToUnpack { expected, found } => {
+                write!(f, "expected {} values but found {}", expected, found)
+            }
+            ErrorKind::Parse(message) => write!(f, "{}", message),
+            ErrorKind::Overflow(computing) => {
+                write!(f, "arithmetic overflow computing {}", computing)
+            }
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(Location { file, line: 0, .. }) => {
+                write!(f, "{}: {}", file.as_deref().unwrap_or("<input>"), self.kind)
+            }
+            Some(location) => {
+                write!(
+                    f,
+                    "{}:{}:{}",
+                    location.file.as_deref().unwrap_or("<input>"),
+                    location.line,
+                    location.column
+                )?;
+                if let Some(offset) = location.offset {
+                    write!(f, " (byte {})", offset)?;
+                }
+                write!(f, ": {}", self.kind)?;
+                if !location.snippet.is_empty() {
+                    write!(f, " (at `{}`)", location.snippet)?;
+                }
+                Ok(())
+            }
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+// `main` functions return `Result<(), SolveError>`, which reports errors with
+// `Debug`; keep that output as readable as `Display`.
+impl fmt::Debug for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(e) => Some(e),
+            ErrorKind::NotParseableAsNumber(e) => Some(e),
+            _ => None,
+        }
+    }
+}
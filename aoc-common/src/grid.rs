@@ -0,0 +1,143 @@
+use std::{collections::HashSet, io::BufRead};
+
+use crate::{input, numbered_lines, Input, SolveError};
+
+pub const COLOR_GREEN: &str = "\x1b[92m";
+pub const COLOR_RED: &str = "\x1b[91m";
+pub const COLOR_YELLOW: &str = "\x1b[93m";
+pub const COLOR_RESET: &str = "\x1b[0m";
+
+pub fn get_all_coordinates(puzzle: &[Vec<char>]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    puzzle
+        .iter()
+        .enumerate()
+        .flat_map(|(x, row)| row.iter().enumerate().map(move |(y, _)| (y, x)))
+}
+
+pub fn build_puzzle_map_from_file(file_path: &str) -> Result<Vec<Vec<char>>, SolveError> {
+    let input = Input::open(file_path)?;
+    build_puzzle_map_from_bytes(&input).map_err(|e| e.with_file(file_path))
+}
+
+/// Reads a rectangular grid of letters, one row per line. Ragged rows are
+/// rejected so the grid helpers below can index any row by `puzzle[0].len()`.
+pub fn build_puzzle_map<R: BufRead>(reader: R) -> Result<Vec<Vec<char>>, SolveError> {
+    puzzle_map_from_lines(numbered_lines(reader).map(|line| line.map(|l| (l.number, l.text))))
+}
+
+/// `build_puzzle_map` over an input already in memory, without copying each
+/// line out first.
+pub fn build_puzzle_map_from_bytes(input: &[u8]) -> Result<Vec<Vec<char>>, SolveError> {
+    let lines = input::text(input)?
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Ok((i + 1, text)));
+    puzzle_map_from_lines(lines)
+}
+
+fn puzzle_map_from_lines<S: AsRef<str>>(
+    lines: impl Iterator<Item = Result<(usize, S), SolveError>>,
+) -> Result<Vec<Vec<char>>, SolveError> {
+    let mut puzzle: Vec<Vec<char>> = Vec::new();
+    for maybe_line in lines {
+        let (number, text) = maybe_line?;
+        let text = text.as_ref();
+        let row: Vec<char> = text.chars().collect();
+        if let Some(first_row) = puzzle.first() {
+            if row.len() != first_row.len() {
+                return Err(SolveError::parse(format!(
+                    "row has {} letters but the first row has {}",
+                    row.len(),
+                    first_row.len()
+                ))
+                .at(number, row.len().min(first_row.len()) + 1, text));
+            }
+        }
+        puzzle.push(row);
+    }
+
+    if puzzle.first().is_none_or(|row| row.is_empty()) {
+        return Err(SolveError::parse("puzzle is empty"));
+    }
+
+    Ok(puzzle)
+}
+
+pub fn new_position(
+    puzzle: &[Vec<char>],
+    x: usize,
+    y: usize,
+    move_x: i32,
+    move_y: i32,
+) -> Option<(usize, usize)> {
+    let x: i32 = x.try_into().unwrap();
+    let y: i32 = y.try_into().unwrap();
+    let new_x: i32 = x + move_x;
+    let new_y: i32 = y + move_y;
+    let puzzle_w: i32 = puzzle[0].len().try_into().unwrap();
+    let puzzle_h: i32 = puzzle.len().try_into().unwrap();
+
+    if new_x < 0 || new_x >= puzzle_w || new_y < 0 || new_y >= puzzle_h {
+        return None;
+    }
+
+    Some((new_x.try_into().unwrap(), new_y.try_into().unwrap()))
+}
+
+pub fn is_valid_coord(puzzle: &[Vec<char>], x: usize, y: usize) -> bool {
+    y < puzzle.len() && x < puzzle[0].len()
+}
+
+pub fn letter_at(puzzle: &[Vec<char>], x: usize, y: usize) -> char {
+    assert!(is_valid_coord(puzzle, x, y));
+
+    puzzle[y][x]
+}
+
+pub fn draw_board(
+    puzzle: &[Vec<char>],
+    highlight: &HashSet<(usize, usize)>,
+    highlight_color: fn(char) -> &'static str,
+) {
+    let h = puzzle.len();
+    let w = puzzle[0].len();
+
+    let mut next = (0, 0);
+
+    println!(" {}", (0..w).map(|i| format!(" {}", i)).collect::<String>());
+    println!(" {}", (1..(w * 2 + 2)).map(|_| '-').collect::<String>());
+
+    print!("0");
+    let mut curr_y = 0;
+    loop {
+        print!("|");
+
+        let should_highlight = highlight.contains(&(next.0, next.1));
+        let c = letter_at(puzzle, next.0, next.1);
+        if should_highlight {
+            print!("{}", highlight_color(c));
+        }
+
+        print!("{}", c);
+
+        if should_highlight {
+            print!("{}", COLOR_RESET)
+        }
+
+        next = (next.0 + 1, next.1);
+
+        if next.0 >= w {
+            curr_y += 1;
+            println!("|");
+            println!(" {}", (1..(w * 2 + 2)).map(|_| '-').collect::<String>());
+            if curr_y < h {
+                print!("{}", curr_y);
+            }
+            next = (0, next.1 + 1);
+        }
+
+        if next.1 >= h {
+            break;
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    ops::Deref,
+    path::Path,
+};
+
+use memmap2::Mmap;
+
+use crate::SolveError;
+
+/// A whole puzzle input held in memory, so solvers can work on a `&[u8]` (or
+/// `&str`) view of it instead of copying it through a reader line by line.
+///
+/// Regular files are memory-mapped. Anything that can't be mapped (stdin,
+/// pipes, FIFOs, empty files) is read into a buffer instead; both give the
+/// same bytes.
+pub enum Input {
+    Mapped(Mmap),
+    Buffered(Vec<u8>),
+}
+
+impl Input {
+    /// Maps `path` into memory, falling back to reading it when it isn't a
+    /// regular file or the mapping fails. Errors carry the file name.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SolveError> {
+        let path = path.as_ref();
+        Self::open_file(path).map_err(|e| SolveError::from(e).with_file(path.display().to_string()))
+    }
+
+    fn open_file(path: &Path) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let metadata = file.metadata()?;
+        if metadata.is_file() && metadata.len() > 0 {
+            // SAFETY: the map is only ever read through `&[u8]`. If another
+            // process truncates or rewrites the file while we hold it, reads
+            // may see the new contents or fault; puzzle inputs aren't edited
+            // while being solved, and that is the usual trade for mapping.
+            if let Ok(map) = unsafe { Mmap::map(&file) } {
+                return Ok(Input::Mapped(map));
+            }
+        }
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        Ok(Input::Buffered(bytes))
+    }
+
+    /// Reads everything `reader` has to offer.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, SolveError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Ok(Input::Buffered(bytes))
+    }
+
+    pub fn stdin() -> Result<Self, SolveError> {
+        Self::read(io::stdin().lock())
+    }
+
+    pub fn is_mapped(&self) -> bool {
+        matches!(self, Input::Mapped(_))
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        match self {
+            Input::Mapped(map) => map,
+            Input::Buffered(bytes) => bytes,
+        }
+    }
+
+    pub fn text(&self) -> Result<&str, SolveError> {
+        text(self.bytes())
+    }
+}
+
+impl Deref for Input {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.bytes()
+    }
+}
+
+/// The `Parsed` form of solvers that scan the corrupted memory as a whole:
+/// one `String`, not one per line. Only `parse` and `parse_bytes` (timed on
+/// their own by `aoc bench`) copy it; `solve_bytes` can scan the input where
+/// it lies through [`text`].
+pub type WholeText = String;
+
+/// `input` as UTF-8, failing like `numbered_lines` would: an I/O error of
+/// kind `InvalidData`, placed on the first line with bad bytes.
+pub fn text(input: &[u8]) -> Result<&str, SolveError> {
+    std::str::from_utf8(input).map_err(|e| {
+        let valid = &input[..e.valid_up_to()];
+        let line = valid.iter().filter(|&&b| b == b'\n').count() + 1;
+        SolveError::from(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "stream did not contain valid UTF-8",
+        ))
+        .at(line, 1, "")
+    })
+}
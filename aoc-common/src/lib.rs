@@ -0,0 +1,23 @@
+pub mod bytes;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod multiset;
+pub mod num;
+pub mod parallel;
+pub mod parse;
+pub mod reports;
+pub mod safety;
+pub mod scan;
+pub mod solution;
+pub mod solver;
+
+pub use bytes::{NumberScanner, Token};
+pub use error::{ErrorKind, Location, SolveError};
+pub use input::Input;
+pub use multiset::OccurrenceCounter;
+pub use num::CheckedInt;
+pub use parse::{numbered_lines, parse_into_number_pair, parse_into_numbers, Line};
+pub use safety::{SafetyPolicy, Verdict};
+pub use solution::{Answer, Solution};
+pub use solver::Solver;
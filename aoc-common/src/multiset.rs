@@ -0,0 +1,169 @@
+use std::{
+    cmp::Reverse,
+    collections::{btree_map, BTreeMap},
+    iter::FromIterator,
+};
+
+/// A multiset: how many times each value was observed. Iteration is in
+/// ascending order of value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OccurrenceCounter<T> {
+    counts: BTreeMap<T, usize>,
+    len: usize,
+}
+
+impl<T: Ord> Default for OccurrenceCounter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> OccurrenceCounter<T> {
+    pub fn new() -> Self {
+        Self {
+            counts: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    pub fn increment_observed_count(&mut self, n: T) {
+        self.add(n, 1);
+    }
+
+    pub fn add(&mut self, n: T, count: usize) {
+        if count > 0 {
+            *self.counts.entry(n).or_insert(0) += count;
+            self.len += count;
+        }
+    }
+
+    pub fn num_occurances(&self, n: &T) -> usize {
+        self.counts.get(n).copied().unwrap_or(0)
+    }
+
+    /// Total number of observations, counting repeats.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of different values observed.
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self.counts.iter())
+    }
+
+    /// Adds every observation in `other` to this counter.
+    pub fn merge(&mut self, other: &Self)
+    where
+        T: Clone,
+    {
+        for (n, count) in other {
+            self.add(n.clone(), count);
+        }
+    }
+
+    /// Values in both, each with the smaller of its two counts.
+    pub fn intersection(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .map(|(n, count)| (n, count.min(other.num_occurances(n))))
+            .filter(|&(_, count)| count > 0)
+            .map(|(n, count)| (n.clone(), count))
+            .collect()
+    }
+
+    /// This counter with `other`'s observations taken away, never going below
+    /// zero.
+    pub fn difference(&self, other: &Self) -> Self
+    where
+        T: Clone,
+    {
+        self.iter()
+            .map(|(n, count)| (n, count.saturating_sub(other.num_occurances(n))))
+            .filter(|&(_, count)| count > 0)
+            .map(|(n, count)| (n.clone(), count))
+            .collect()
+    }
+
+    /// The `k` most frequent values, most frequent first. Ties go to the
+    /// smaller value.
+    pub fn top_k(&self, k: usize) -> Vec<(&T, usize)> {
+        let mut by_count: Vec<(&T, usize)> = self.iter().collect();
+        by_count.sort_by_key(|&(n, count)| (Reverse(count), n));
+        by_count.truncate(k);
+        by_count
+    }
+}
+
+impl<T: Ord> Extend<T> for OccurrenceCounter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for n in iter {
+            self.increment_observed_count(n);
+        }
+    }
+}
+
+impl<T: Ord> Extend<(T, usize)> for OccurrenceCounter<T> {
+    fn extend<I: IntoIterator<Item = (T, usize)>>(&mut self, iter: I) {
+        for (n, count) in iter {
+            self.add(n, count);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for OccurrenceCounter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Ord> FromIterator<(T, usize)> for OccurrenceCounter<T> {
+    fn from_iter<I: IntoIterator<Item = (T, usize)>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a OccurrenceCounter<T> {
+    type Item = (&'a T, usize);
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Each distinct value with its count, in ascending order of value.
+pub struct Iter<'a, T>(btree_map::Iter<'a, T, usize>);
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(n, count)| (n, *count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(n, count)| (n, *count))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
@@ -0,0 +1,63 @@
+use std::{fmt, hash::Hash, num::ParseIntError, str::FromStr};
+
+/// Integer types a solver can be run at. Every operation is checked so
+/// solvers can report overflow instead of silently wrapping in release builds.
+pub trait CheckedInt:
+    Copy + Ord + Hash + Default + fmt::Display + fmt::Debug + FromStr<Err = ParseIntError> + 'static
+{
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// `|self - rhs|`, or `None` if it doesn't fit in `Self`.
+    fn checked_abs_diff(self, rhs: Self) -> Option<Self>;
+    fn from_count(count: usize) -> Option<Self>;
+
+    /// Bytes in the fixed-width encoding of `write_le` and `read_le`.
+    const WIDTH: usize;
+    /// Writes `self` little-endian into `buf`, which is `WIDTH` bytes long.
+    fn write_le(self, buf: &mut [u8]);
+    /// Reads back a value `write_le` wrote into `buf`.
+    fn read_le(buf: &[u8]) -> Self;
+}
+
+macro_rules! impl_checked_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedInt for $t {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_abs_diff(self, rhs: Self) -> Option<Self> {
+                    Self::try_from(self.abs_diff(rhs)).ok()
+                }
+
+                fn from_count(count: usize) -> Option<Self> {
+                    Self::try_from(count).ok()
+                }
+
+                const WIDTH: usize = std::mem::size_of::<$t>();
+
+                fn write_le(self, buf: &mut [u8]) {
+                    buf.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(buf: &[u8]) -> Self {
+                    let mut bytes = [0; std::mem::size_of::<$t>()];
+                    bytes.copy_from_slice(buf);
+                    <$t>::from_le_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_int!(i32, i64, u32, u64, i128);
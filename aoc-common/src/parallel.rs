@@ -0,0 +1,201 @@
+use std::thread::{self, Scope, ScopedJoinHandle};
+
+use crate::{CheckedInt, SolveError};
+
+pub fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// How many threads to actually use when asked for `threads`: 0 means every
+/// available core, and more threads than cores are never started. Every
+/// function here that takes a thread count goes through this.
+pub fn clamp_threads(threads: usize) -> usize {
+    match threads {
+        0 => available_threads(),
+        n => n.min(available_threads()),
+    }
+}
+
+/// Runs `f` on a new thread in `scope`, failing instead of panicking when the
+/// thread can't be started.
+fn spawn<'scope, 'env, T, F>(
+    scope: &'scope Scope<'scope, 'env>,
+    f: F,
+) -> Result<ScopedJoinHandle<'scope, T>, SolveError>
+where
+    T: Send + 'scope,
+    F: FnOnce() -> T + Send + 'scope,
+{
+    Ok(thread::Builder::new().spawn_scoped(scope, f)?)
+}
+
+/// Splits `input` into at most `chunks` pieces that each end at a line break
+/// (so never more pieces than lines).
+pub fn line_chunks(input: &[u8], chunks: usize) -> Vec<&[u8]> {
+    let chunks = chunks.clamp(1, input.len().max(1));
+    let target = input.len().div_ceil(chunks);
+    let mut pieces = Vec::with_capacity(chunks);
+    let mut rest = input;
+    while !rest.is_empty() {
+        let end = match rest
+            .get(target..)
+            .and_then(|tail| tail.iter().position(|&b| b == b'\n'))
+        {
+            Some(newline) => target + newline + 1,
+            None => rest.len(),
+        };
+        let (piece, tail) = rest.split_at(end);
+        pieces.push(piece);
+        rest = tail;
+    }
+    pieces
+}
+
+/// Parses line-aligned chunks of `input` on separate threads, returning the
+/// results in input order. If several chunks fail, the error from the earliest
+/// one is returned, with its line number and byte offset counted from the start
+/// of `input`. Fails if a thread can't be started.
+pub fn parse_chunks<T, F>(input: &[u8], threads: usize, parse: F) -> Result<Vec<T>, SolveError>
+where
+    T: Send,
+    F: Fn(&[u8]) -> Result<T, SolveError> + Sync,
+{
+    let chunks = line_chunks(input, clamp_threads(threads));
+    let results: Vec<Result<T, SolveError>> = thread::scope(|s| {
+        let handles = chunks
+            .iter()
+            .map(|chunk| spawn(s, || parse(chunk)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok::<_, SolveError>(
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("parser thread panicked"))
+                .collect(),
+        )
+    })?;
+
+    let (mut lines_before, mut bytes_before) = (0, 0);
+    let mut parsed = Vec::with_capacity(results.len());
+    for (chunk, result) in chunks.iter().zip(results) {
+        parsed.push(result.map_err(|e| e.after(lines_before, bytes_before))?);
+        lines_before += chunk.iter().filter(|&&b| b == b'\n').count();
+        bytes_before += chunk.len();
+    }
+    Ok(parsed)
+}
+
+/// Sorts runs of `values` on separate threads (no more threads than values),
+/// then merges neighbouring runs pairwise (also in parallel) until one run is
+/// left. A single run is sorted in place on the calling thread. Fails if a
+/// thread can't be started, leaving `values` unsorted.
+pub fn par_sort<T: Ord + Copy + Send + Sync>(
+    values: &mut [T],
+    threads: usize,
+) -> Result<(), SolveError> {
+    let mut run_len = values.len().div_ceil(clamp_threads(threads)).max(1);
+    if run_len >= values.len() {
+        values.sort_unstable();
+        return Ok(());
+    }
+
+    thread::scope(|s| {
+        for run in values.chunks_mut(run_len) {
+            spawn(s, || run.sort_unstable())?;
+        }
+        Ok::<_, SolveError>(())
+    })?;
+
+    let mut merged = values.to_vec();
+    while run_len < values.len() {
+        thread::scope(|s| {
+            for (pair, out) in values
+                .chunks(run_len * 2)
+                .zip(merged.chunks_mut(run_len * 2))
+            {
+                spawn(s, move || {
+                    let (left, right) = pair.split_at(run_len.min(pair.len()));
+                    merge_into(left, right, out);
+                })?;
+            }
+            Ok::<_, SolveError>(())
+        })?;
+        values.copy_from_slice(&merged);
+        run_len *= 2;
+    }
+    Ok(())
+}
+
+fn merge_into<T: Ord + Copy>(left: &[T], right: &[T], out: &mut [T]) {
+    let (mut i, mut j) = (0, 0);
+    for slot in out.iter_mut() {
+        if j == right.len() || (i < left.len() && left[i] <= right[j]) {
+            *slot = left[i];
+            i += 1;
+        } else {
+            *slot = right[j];
+            j += 1;
+        }
+    }
+}
+
+/// Sums `term(0) .. term(len)` across threads. Returns `None` unless it can
+/// show that summing the terms one by one in order would never overflow or
+/// hit a `None` term, so callers can fall back to their sequential sum and get
+/// exactly the same result (or error) either way. A thread that can't be
+/// started also gives `None`.
+pub fn par_checked_sum<T, F>(len: usize, threads: usize, term: F) -> Option<T>
+where
+    T: CheckedInt + Send,
+    F: Fn(usize) -> Option<T> + Sync,
+{
+    let chunk_len = len.div_ceil(clamp_threads(threads)).max(1);
+    let chunks: Vec<Option<PartialSum<T>>> = thread::scope(|s| {
+        let handles = (0..len)
+            .step_by(chunk_len)
+            .map(|start| {
+                let term = &term;
+                spawn(s, move || {
+                    PartialSum::over(start..len.min(start + chunk_len), term)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        Some(
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("sum thread panicked"))
+                .collect(),
+        )
+    })?;
+
+    // Every running total inside a chunk lies between the total before it
+    // plus the chunk's lowest and highest running totals.
+    chunks.into_iter().try_fold(T::default(), |total, chunk| {
+        let chunk = chunk?;
+        total.checked_add(chunk.lowest)?;
+        total.checked_add(chunk.highest)?;
+        total.checked_add(chunk.sum)
+    })
+}
+
+struct PartialSum<T> {
+    sum: T,
+    lowest: T,
+    highest: T,
+}
+
+impl<T: CheckedInt> PartialSum<T> {
+    fn over(range: std::ops::Range<usize>, term: impl Fn(usize) -> Option<T>) -> Option<Self> {
+        let mut partial = PartialSum {
+            sum: T::default(),
+            lowest: T::default(),
+            highest: T::default(),
+        };
+        for i in range {
+            partial.sum = partial.sum.checked_add(term(i)?)?;
+            partial.lowest = partial.lowest.min(partial.sum);
+            partial.highest = partial.highest.max(partial.sum);
+        }
+        Some(partial)
+    }
+}
@@ -0,0 +1,69 @@
+use std::{io::BufRead, num::ParseIntError, str::FromStr};
+
+use crate::{error::ErrorKind, SolveError};
+
+pub struct Line {
+    pub number: usize,
+    pub text: String,
+}
+
+impl Line {
+    /// Every whitespace separated value on the line, failing at the first
+    /// token that is not a number.
+    pub fn numbers<T: FromStr<Err = ParseIntError>>(&self) -> Result<Vec<T>, SolveError> {
+        tokens_with_columns(&self.text)
+            .map(|(column, token)| {
+                token.parse().map_err(|e| {
+                    SolveError::new(ErrorKind::NotParseableAsNumber(e)).at(
+                        self.number,
+                        column,
+                        token,
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// Like `BufRead::lines`, but each line remembers its 1-based line number so
+/// parse errors can point at it.
+pub fn numbered_lines<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Line, SolveError>> {
+    reader.lines().enumerate().map(|(i, line)| {
+        let number = i + 1;
+        line.map(|text| Line { number, text })
+            .map_err(|e| SolveError::from(e).at(number, 1, ""))
+    })
+}
+
+/// Whitespace separated tokens of `text` paired with their 1-based column.
+pub fn tokens_with_columns(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_whitespace().map(move |token| {
+        let offset = token.as_ptr() as usize - text.as_ptr() as usize;
+        (text[..offset].chars().count() + 1, token)
+    })
+}
+
+pub fn parse_into_numbers<T: FromStr<Err = ParseIntError>>(
+    line: Result<Line, SolveError>,
+) -> Result<Vec<T>, SolveError> {
+    line.and_then(|line| line.numbers())
+}
+
+pub fn parse_into_number_pair<T: FromStr<Err = ParseIntError>>(
+    line: Result<Line, SolveError>,
+) -> Result<(T, T), SolveError> {
+    let line = line?;
+    let values = line.numbers()?;
+    let found = values.len();
+    let mut values = values.into_iter();
+    match (values.next(), values.next(), values.next()) {
+        (Some(a), Some(b), None) => Ok((a, b)),
+        _ => Err(
+            SolveError::new(ErrorKind::WrongNumValuesToUnpack { expected: 2, found }).at(
+                line.number,
+                1,
+                line.text.trim(),
+            ),
+        ),
+    }
+}
@@ -0,0 +1,57 @@
+//! Day 2 input: one report per line, each a list of levels, read strictly or
+//! leniently.
+//!
+//! Strict reading stops at the first bad number and reads a blank line as a
+//! report with no levels, which is safe under the part 1 rule but not the
+//! part 2 one (there is no level for the Problem Dampener to remove). Lenient
+//! reading sets malformed lines aside and skips blank lines, which then hold no
+//! report at all. Either way a single level is a report, safe in both parts.
+
+use std::io::BufRead;
+
+use crate::{NumberScanner, SolveError};
+
+pub fn parse_reports<R: BufRead>(mut reader: R) -> Result<Vec<Vec<i32>>, SolveError> {
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input)?;
+    parse_report_bytes(&input)
+}
+
+pub fn parse_report_bytes(input: &[u8]) -> Result<Vec<Vec<i32>>, SolveError> {
+    let mut scanner = NumberScanner::new(input);
+    let mut levels = Vec::new();
+    let mut reports = Vec::new();
+    while let Some(line) = scanner.read_line(&mut levels) {
+        line?;
+        reports.push(levels.clone());
+    }
+    Ok(reports)
+}
+
+/// Reports from the lines that parse, with malformed and blank lines set
+/// aside instead of failing the whole input.
+#[derive(Debug, Default)]
+pub struct LenientReports {
+    pub reports: Vec<Vec<i32>>,
+    /// Why each malformed line was skipped, located at its first bad number.
+    pub malformed: Vec<SolveError>,
+    /// Lines with no levels at all.
+    pub blank_lines: Vec<usize>,
+}
+
+pub fn parse_report_bytes_leniently(input: &[u8]) -> LenientReports {
+    let mut scanner = NumberScanner::new(input);
+    let mut levels = Vec::new();
+    let mut parsed = LenientReports::default();
+    while let Some(line) = scanner.read_line(&mut levels) {
+        match line {
+            Ok(number) if levels.is_empty() => parsed.blank_lines.push(number),
+            Ok(_) => parsed.reports.push(levels.clone()),
+            Err(e) => {
+                parsed.malformed.push(e);
+                scanner.skip_line();
+            }
+        }
+    }
+    parsed
+}
@@ -0,0 +1,236 @@
+use std::{fmt, str::FromStr};
+
+/// The rule a day 2 report must follow to count as safe. The default is the
+/// puzzle's: every step between neighbouring levels goes the same way and
+/// changes the level by 1 to 3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// Smallest allowed change between neighbouring levels.
+    pub min_step: u32,
+    /// Largest allowed change between neighbouring levels.
+    pub max_step: u32,
+    pub direction: Direction,
+    /// Largest allowed change from the first level to the last, if limited.
+    pub max_drift: Option<u64>,
+}
+
+/// Which ways a report's levels may move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Every step goes up.
+    Increasing,
+    /// Every step goes down.
+    Decreasing,
+    /// Every step goes up, or every step goes down.
+    Either,
+    /// Like `Either`, but flat steps are allowed too (as long as `min_step`
+    /// is 0).
+    NonStrict,
+}
+
+/// The way a report is assumed to move while checking its steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+}
+
+/// Whether a report is safe, and if not, where and why it first goes wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    Unsafe {
+        /// The first level that breaks the policy, following whichever trend
+        /// the report keeps to the longest. For `Violation::Drift` this is the
+        /// last level.
+        at: usize,
+        violation: Violation,
+        /// A level whose removal makes the report safe, if there is one.
+        fixed_by: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// A step goes the wrong way.
+    Direction,
+    /// A step is too small or too large (including a flat step when flat
+    /// steps are too small).
+    StepSize,
+    /// The last level is too far from the first.
+    Drift,
+}
+
+impl Verdict {
+    pub fn is_safe(&self) -> bool {
+        matches!(self, Verdict::Safe)
+    }
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            max_drift: None,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    pub fn is_safe(&self, report: &[i32]) -> bool {
+        self.levels_are_safe(report.iter().copied())
+    }
+
+    /// `is_safe` for levels that aren't laid out in one slice, such as a
+    /// report with one level skipped.
+    pub fn levels_are_safe<I: Iterator<Item = i32> + Clone>(&self, levels: I) -> bool {
+        self.drift_allowed(levels.clone().next(), levels.clone().last())
+            && self
+                .trends()
+                .iter()
+                .any(|&trend| self.first_bad_step(levels.clone(), trend).is_none())
+    }
+
+    /// The index of the first level whose step to the next one is not
+    /// allowed in a report following `trend`.
+    pub fn first_bad_step<I: Iterator<Item = i32> + Clone>(
+        &self,
+        levels: I,
+        trend: Trend,
+    ) -> Option<usize> {
+        levels
+            .clone()
+            .zip(levels.skip(1))
+            .position(|(previous, next)| !self.allows_step(step(previous, next), trend))
+    }
+
+    pub fn verdict(&self, report: &[i32]) -> Verdict {
+        if self.is_safe(report) {
+            return Verdict::Safe;
+        }
+
+        let mut furthest = None;
+        for &trend in self.trends() {
+            match self.first_bad_step(report.iter().copied(), trend) {
+                // Every step is fine for this trend, so the drift must be what
+                // failed.
+                None => {
+                    return Verdict::Unsafe {
+                        at: report.len() - 1,
+                        violation: Violation::Drift,
+                        fixed_by: self.fixing_removal(report),
+                    }
+                }
+                Some(i) => furthest = furthest.max(Some(i)),
+            }
+        }
+        let i = furthest.expect("a policy allows at least one trend");
+        let violation = if self.allows_size(step(report[i], report[i + 1])) {
+            Violation::Direction
+        } else {
+            Violation::StepSize
+        };
+        Verdict::Unsafe {
+            at: i + 1,
+            violation,
+            fixed_by: self.fixing_removal(report),
+        }
+    }
+
+    /// A level whose removal leaves `report` safe, if there is one.
+    ///
+    /// Only a few removals can help, so only those are tried. For each trend
+    /// the report might follow, either one of the two levels around its first
+    /// bad step has to go, or there is no bad step and dropping the first or
+    /// last level is at least as good as dropping any other (the steps stay
+    /// allowed and the drift can only shrink). Each try is one pass without
+    /// allocating, so this is linear in the report's length.
+    pub fn fixing_removal(&self, report: &[i32]) -> Option<usize> {
+        self.trends().iter().find_map(|&trend| {
+            let candidates = match self.first_bad_step(report.iter().copied(), trend) {
+                Some(i) => [i, i + 1],
+                None => [0, report.len().saturating_sub(1)],
+            };
+            candidates
+                .into_iter()
+                .filter(|&skip| skip < report.len())
+                .find(|&skip| self.levels_are_safe(without(report, skip)))
+        })
+    }
+
+    /// The trends a safe report may follow.
+    pub fn trends(&self) -> &'static [Trend] {
+        match self.direction {
+            Direction::Increasing => &[Trend::Increasing],
+            Direction::Decreasing => &[Trend::Decreasing],
+            Direction::Either | Direction::NonStrict => &[Trend::Increasing, Trend::Decreasing],
+        }
+    }
+
+    /// Whether a single step from one level to the next (`next - previous`)
+    /// is allowed in a report following `trend`.
+    pub fn allows_step(&self, step: i64, trend: Trend) -> bool {
+        let strict = self.direction != Direction::NonStrict;
+        let right_way = match trend {
+            Trend::Increasing => step > 0 || (!strict && step == 0),
+            Trend::Decreasing => step < 0 || (!strict && step == 0),
+        };
+        right_way && self.allows_size(step)
+    }
+
+    fn allows_size(&self, step: i64) -> bool {
+        (u64::from(self.min_step)..=u64::from(self.max_step)).contains(&step.unsigned_abs())
+    }
+
+    pub fn within_drift(&self, report: &[i32]) -> bool {
+        self.drift_allowed(report.first().copied(), report.last().copied())
+    }
+
+    fn drift_allowed(&self, first: Option<i32>, last: Option<i32>) -> bool {
+        match (self.max_drift, first, last) {
+            (Some(max_drift), Some(first), Some(last)) => {
+                step(first, last).unsigned_abs() <= max_drift
+            }
+            _ => true,
+        }
+    }
+}
+
+/// The levels of `report` except the one at `skip`.
+fn without(report: &[i32], skip: usize) -> impl Iterator<Item = i32> + Clone + '_ {
+    report[..skip].iter().chain(&report[skip + 1..]).copied()
+}
+
+/// The change from `previous` to `next`, widened so that it can't overflow.
+pub fn step(previous: i32, next: i32) -> i64 {
+    i64::from(next) - i64::from(previous)
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "increasing" => Ok(Direction::Increasing),
+            "decreasing" => Ok(Direction::Decreasing),
+            "either" => Ok(Direction::Either),
+            "non-strict" => Ok(Direction::NonStrict),
+            _ => Err(format!(
+                "unknown direction '{}' (expected increasing, decreasing, either or non-strict)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Violation::Direction => "direction change",
+            Violation::StepSize => "step out of range",
+            Violation::Drift => "too much drift",
+        })
+    }
+}
@@ -0,0 +1,49 @@
+use crate::SolveError;
+
+pub fn consume_remainder(input: &str) -> &str {
+    let (_, no_remainder) = input.split_at(input.len());
+    no_remainder
+}
+
+pub fn scan_for_next_token_and_consume<'a>(
+    token: &str,
+    input: &'a str,
+) -> Result<&'a str, &'a str> {
+    input.split_once(token).map_or_else(
+        || Err(consume_remainder(input)),
+        |(_, remaining)| Ok(remaining),
+    )
+}
+
+pub fn consume_token<'a>(token: &str, input: &'a str) -> Result<&'a str, &'a str> {
+    if input.len() < token.len() {
+        return Err(consume_remainder(input));
+    }
+
+    input.strip_prefix(token).ok_or(input)
+}
+
+/// Reads a `mul` operand: 1 to 3 ASCII digits. A longer run of digits (or a
+/// non-ASCII numeral) is not a match, so `input` is handed back unchanged.
+pub fn read_digits(input: &str) -> Result<(i32, &str), &str> {
+    let len = input.bytes().take_while(u8::is_ascii_digit).count();
+    if !(1..=3).contains(&len) {
+        return Err(input);
+    }
+
+    let (digits, remainder) = input.split_at(len);
+    digits.parse().map(|n| (n, remainder)).map_err(|_| input)
+}
+
+/// `total + multiplicand * multiplier`, or an overflow error naming `what`.
+/// A product of two `i32`s always fits in an `i64`; only the sum can overflow.
+pub fn add_product(
+    total: i64,
+    multiplicand: i32,
+    multiplier: i32,
+    what: &'static str,
+) -> Result<i64, SolveError> {
+    total
+        .checked_add(i64::from(multiplicand) * i64::from(multiplier))
+        .ok_or_else(|| SolveError::overflow(what))
+}
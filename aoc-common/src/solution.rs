@@ -0,0 +1,73 @@
+use std::{fmt, io::BufRead};
+
+use crate::SolveError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_int!(i32, i64, u32, u64, i128);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Int(n as i128)
+    }
+}
+
+/// A puzzle solution that can be driven from any buffered source: a file,
+/// stdin, or an in-memory `&[u8]`. Reading the input and computing the answer
+/// are separate steps so they can be measured on their own.
+///
+/// Input that is already in memory (see [`crate::Input`]) goes through
+/// `parse_bytes`, which solutions override when they can read the bytes
+/// directly rather than through a reader.
+pub trait Solution {
+    const DAY: u8;
+    const PART: u8;
+    const NAME: &'static str;
+    /// Set by solutions that read their input in one pass without holding
+    /// it all in memory, so the runner hands them a reader instead of
+    /// loading the whole input first.
+    const STREAMS_INPUT: bool = false;
+
+    type Parsed: 'static;
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError>;
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        self.parse(input)
+    }
+
+    fn compute(&self, parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+
+    fn solve<R: BufRead>(&self, input: R) -> Result<Answer, SolveError> {
+        let parsed = self.parse(input)?;
+        self.compute(&parsed)
+    }
+
+    fn solve_bytes(&self, input: &[u8]) -> Result<Answer, SolveError> {
+        let parsed = self.parse_bytes(input)?;
+        self.compute(&parsed)
+    }
+}
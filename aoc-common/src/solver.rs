@@ -0,0 +1,59 @@
+use std::{any::Any, io::BufRead};
+
+use crate::{Answer, Solution, SolveError};
+
+/// Object-safe view of a [`Solution`] so the runner can keep every day in one
+/// registry. The parsed input is type-erased so `parse` and `compute` can be
+/// timed separately.
+pub trait Solver {
+    fn day(&self) -> u8;
+    fn part(&self) -> u8;
+    fn name(&self) -> &'static str;
+    fn streams_input(&self) -> bool;
+    fn solve(&self, input: &mut dyn BufRead) -> Result<Answer, SolveError>;
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Box<dyn Any>, SolveError>;
+    fn solve_bytes(&self, input: &[u8]) -> Result<Answer, SolveError>;
+    fn parse_bytes(&self, input: &[u8]) -> Result<Box<dyn Any>, SolveError>;
+    fn compute(&self, parsed: &dyn Any) -> Result<Answer, SolveError>;
+}
+
+impl<S: Solution> Solver for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn part(&self) -> u8 {
+        S::PART
+    }
+
+    fn name(&self) -> &'static str {
+        S::NAME
+    }
+
+    fn streams_input(&self) -> bool {
+        S::STREAMS_INPUT
+    }
+
+    fn solve(&self, input: &mut dyn BufRead) -> Result<Answer, SolveError> {
+        Solution::solve(self, input)
+    }
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Box<dyn Any>, SolveError> {
+        Solution::parse(self, input).map(|parsed| Box::new(parsed) as Box<dyn Any>)
+    }
+
+    fn solve_bytes(&self, input: &[u8]) -> Result<Answer, SolveError> {
+        Solution::solve_bytes(self, input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Box<dyn Any>, SolveError> {
+        Solution::parse_bytes(self, input).map(|parsed| Box::new(parsed) as Box<dyn Any>)
+    }
+
+    fn compute(&self, parsed: &dyn Any) -> Result<Answer, SolveError> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("compute called with input parsed by a different solver");
+        Solution::compute(self, parsed)
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::{Generated, Rng};
+
+/// `size` lines of two location IDs. About half of the right column is drawn
+/// from the left column so the similarity score is not trivially zero.
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let left: Vec<i64> = (0..size).map(|_| rng.between(10_000, 99_999)).collect();
+    let right: Vec<i64> = (0..size)
+        .map(|_| {
+            if !left.is_empty() && rng.chance(50) {
+                *rng.pick(&left)
+            } else {
+                rng.between(10_000, 99_999)
+            }
+        })
+        .collect();
+
+    let input = left
+        .iter()
+        .zip(&right)
+        .map(|(l, r)| format!("{}   {}\n", l, r))
+        .collect();
+
+    let mut left_sorted = left.clone();
+    let mut right_sorted = right.clone();
+    left_sorted.sort_unstable();
+    right_sorted.sort_unstable();
+    let distance: i128 = left_sorted
+        .iter()
+        .zip(&right_sorted)
+        .map(|(l, r)| i128::from((l - r).abs()))
+        .sum();
+
+    let mut right_counts: HashMap<i64, i128> = HashMap::new();
+    for r in &right {
+        *right_counts.entry(*r).or_default() += 1;
+    }
+    let similarity: i128 = left
+        .iter()
+        .map(|l| i128::from(*l) * right_counts.get(l).copied().unwrap_or(0))
+        .sum();
+
+    Generated {
+        input,
+        part1: distance.into(),
+        part2: similarity.into(),
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Generated, Rng};
+
+enum Kind {
+    Safe,
+    Dampenable,
+    Unsafe,
+}
+
+/// `size` reports, each built to be safe, safe only with the Problem
+/// Dampener, or unsafe under both rules.
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let mut safe = 0usize;
+    let mut dampened_safe = 0usize;
+
+    for _ in 0..size {
+        let kind = match rng.below(3) {
+            0 => Kind::Safe,
+            1 => Kind::Dampenable,
+            _ => Kind::Unsafe,
+        };
+        let mut report = safe_report(&mut rng);
+        match kind {
+            Kind::Safe => {
+                safe += 1;
+                dampened_safe += 1;
+            }
+            Kind::Dampenable => {
+                add_removable_bad_level(&mut rng, &mut report);
+                dampened_safe += 1;
+            }
+            Kind::Unsafe => add_unremovable_fault(&mut rng, &mut report),
+        }
+
+        let line: Vec<String> = report.iter().map(|l| l.to_string()).collect();
+        input.push_str(&line.join(" "));
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        part1: safe.into(),
+        part2: dampened_safe.into(),
+    }
+}
+
+fn safe_report(rng: &mut Rng) -> Vec<i64> {
+    let len = rng.between(5, 8);
+    let direction = if rng.chance(50) { 1 } else { -1 };
+    let mut level = if direction > 0 {
+        rng.between(1, 40)
+    } else {
+        rng.between(60, 99)
+    };
+    (0..len)
+        .map(|i| {
+            if i > 0 {
+                level += direction * rng.between(1, 3);
+            }
+            level
+        })
+        .collect()
+}
+
+/// Inserts one level that makes the report unsafe, but removing that level
+/// restores the original safe report.
+fn add_removable_bad_level(rng: &mut Rng, report: &mut Vec<i64>) {
+    let i = rng.below(report.len() as u64) as usize;
+    if rng.chance(50) {
+        // Repeat a level: a step of zero.
+        report.insert(i, report[i]);
+    } else {
+        // Step backwards against the direction of the report.
+        let direction = (report[1] - report[0]).signum();
+        report.insert(i + 1, report[i] - direction);
+    }
+}
+
+/// Adds faults no single removal can fix: either two separate repeated
+/// levels, or a jump of more than 3 between two interior levels.
+fn add_unremovable_fault(rng: &mut Rng, report: &mut Vec<i64>) {
+    if rng.chance(50) {
+        let first = rng.below(report.len() as u64) as usize;
+        let second = rng.below(report.len() as u64) as usize;
+        report.insert(second.max(first), report[second.max(first)]);
+        report.insert(second.min(first), report[second.min(first)]);
+    } else {
+        let direction = (report[1] - report[0]).signum();
+        let gap_after = rng.between(1, report.len() as i64 - 3) as usize;
+        let jump = direction * rng.between(4, 9);
+        for level in report.iter_mut().skip(gap_after + 1) {
+            *level += jump;
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{Generated, Rng};
+
+// Noise never contains the letters of `mul`, `do()` or `don't()`, so the only
+// instructions in the output are the ones planted on purpose.
+const NOISE: &[u8] = b"!@#$%^&*[]{}<>?;:+-=_ ,xyzwhe";
+
+// Near misses of real instructions. None of them parses as a `mul`, and each
+// ends in a character that can't continue a number.
+const DECOYS: &[&str] = &[
+    "mul(4*",
+    "mul[3,7]",
+    "mul ( 2 , 4 )",
+    "?(12,34)",
+    "mul(32,64]",
+    "mul(6,9!",
+    "do_not",
+    "don't",
+];
+
+const TOKENS_PER_LINE: usize = 200;
+
+/// `size` lines of corrupted memory with `mul(a,b)`, `do()` and `don't()`
+/// instructions planted among noise and decoys.
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let mut all_products: i128 = 0;
+    let mut enabled_products: i128 = 0;
+    let mut enabled = true;
+
+    for _ in 0..size {
+        for _ in 0..TOKENS_PER_LINE {
+            match rng.below(20) {
+                0..=3 => {
+                    let (a, b) = (rng.between(1, 999), rng.between(1, 999));
+                    input.push_str(&format!("mul({},{})", a, b));
+                    all_products += i128::from(a * b);
+                    if enabled {
+                        enabled_products += i128::from(a * b);
+                    }
+                }
+                4 => {
+                    input.push_str("do()");
+                    enabled = true;
+                }
+                5 => {
+                    input.push_str("don't()");
+                    enabled = false;
+                }
+                6 | 7 => input.push_str(rng.pick::<&str>(DECOYS)),
+                _ => input.push(*rng.pick(NOISE) as char),
+            }
+        }
+        input.push('\n');
+    }
+
+    Generated {
+        input,
+        part1: all_products.into(),
+        part2: enabled_products.into(),
+    }
+}
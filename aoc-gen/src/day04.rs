@@ -0,0 +1,114 @@
+use crate::{Generated, Rng};
+
+// Letters that can never be part of XMAS or X-MAS.
+const FILLER: &[u8] = b"BCDEFGHIJKLNOPQRTUVWYZ";
+
+// The grid is split into 5x5 tiles: a 4x4 area that holds at most one planted
+// word, plus a row and column of filler. Every straight line or X shape that
+// crosses from one tile to another passes through filler, so words can't
+// form across tiles and the planted counts are exact.
+const TILE: usize = 5;
+const DECOYS: [&[u8]; 3] = [b"XMA", b"MAS", b"SAM"];
+const AREA: usize = 4;
+
+const DIRECTIONS: [(i64, i64); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+];
+
+/// A `size` by `size` letter grid with XMAS words (in any of the eight
+/// directions), X-MAS crosses and partial decoys planted in it.
+pub fn generate(seed: u64, size: usize) -> Generated {
+    let mut rng = Rng::new(seed);
+    let mut grid: Vec<Vec<u8>> = (0..size)
+        .map(|_| (0..size).map(|_| *rng.pick(FILLER)).collect())
+        .collect();
+    let mut xmas = 0usize;
+    let mut x_mas = 0usize;
+
+    let tiles = size / TILE;
+    for tile_y in 0..tiles {
+        for tile_x in 0..tiles {
+            let origin = (tile_x * TILE, tile_y * TILE);
+            match rng.below(10) {
+                0..=3 => {
+                    plant_line(&mut rng, &mut grid, origin, b"XMAS");
+                    xmas += 1;
+                }
+                4..=6 => {
+                    plant_cross(&mut rng, &mut grid, origin);
+                    x_mas += 1;
+                }
+                7 => {
+                    let decoy = *rng.pick(&DECOYS);
+                    plant_line(&mut rng, &mut grid, origin, decoy);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let input = grid
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect();
+
+    Generated {
+        input,
+        part1: xmas.into(),
+        part2: x_mas.into(),
+    }
+}
+
+/// Writes `word` in a random direction inside the 4x4 area of a tile.
+fn plant_line(rng: &mut Rng, grid: &mut [Vec<u8>], (x0, y0): (usize, usize), word: &[u8]) {
+    let (dx, dy) = *rng.pick(&DIRECTIONS);
+    let span = word.len() as i64 - 1;
+    let start_range = |d: i64| {
+        if d > 0 {
+            (0, AREA as i64 - 1 - span)
+        } else if d < 0 {
+            (span, AREA as i64 - 1)
+        } else {
+            (0, AREA as i64 - 1)
+        }
+    };
+    let (min_x, max_x) = start_range(dx);
+    let (min_y, max_y) = start_range(dy);
+    let (sx, sy) = (rng.between(min_x, max_x), rng.between(min_y, max_y));
+
+    for (i, letter) in word.iter().enumerate() {
+        let x = (sx + dx * i as i64) as usize;
+        let y = (sy + dy * i as i64) as usize;
+        grid[y0 + y][x0 + x] = *letter;
+    }
+}
+
+/// Writes an X-MAS (two MAS on the diagonals of a 3x3 square, each read in
+/// either direction) inside the 4x4 area of a tile.
+fn plant_cross(rng: &mut Rng, grid: &mut [Vec<u8>], (x0, y0): (usize, usize)) {
+    let x = x0 + rng.below(2) as usize;
+    let y = y0 + rng.below(2) as usize;
+    let (a, b) = if rng.chance(50) {
+        (b'M', b'S')
+    } else {
+        (b'S', b'M')
+    };
+    let (c, d) = if rng.chance(50) {
+        (b'M', b'S')
+    } else {
+        (b'S', b'M')
+    };
+
+    grid[y + 1][x + 1] = b'A';
+    grid[y][x] = a;
+    grid[y + 2][x + 2] = b;
+    grid[y][x + 2] = c;
+    grid[y + 2][x] = d;
+}
@@ -0,0 +1,45 @@
+//! Deterministic generators for each day's puzzle input format. Every
+//! generator builds its input so the expected answers are known by
+//! construction, which lets the solvers be checked on inputs far larger than
+//! the real puzzles.
+
+use aoc_common::Answer;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+mod rng;
+
+pub use rng::Rng;
+
+pub struct Generated {
+    pub input: String,
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+/// Roughly the size of the real puzzle input for `day`, in the units
+/// `generate` uses.
+pub fn puzzle_size(day: u8) -> Option<usize> {
+    match day {
+        1 => Some(1000),
+        2 => Some(1000),
+        3 => Some(6),
+        4 => Some(140),
+        _ => None,
+    }
+}
+
+/// Generates an input for `day`. What `size` counts depends on the day: lines
+/// for day 1, reports for day 2, lines of corrupted memory for day 3 and the
+/// side length of the letter grid for day 4.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<Generated> {
+    match day {
+        1 => Some(day01::generate(seed, size)),
+        2 => Some(day02::generate(seed, size)),
+        3 => Some(day03::generate(seed, size)),
+        4 => Some(day04::generate(seed, size)),
+        _ => None,
+    }
+}
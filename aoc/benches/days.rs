@@ -0,0 +1,141 @@
+//! Benchmarks for the core function of each day on synthetic inputs scaled
+//! 10x-1000x beyond the real puzzle size.
+
+use std::hint::black_box;
+
+use aoc_common::grid::build_puzzle_map;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const SCALES: [usize; 3] = [10, 100, 1000];
+
+fn generated_input(day: u8, scale: usize) -> String {
+    let size = aoc_gen::puzzle_size(day).unwrap();
+    // Days 1-3 scale linearly with their size; day 4's size is the side of a
+    // square grid, so scale its area instead.
+    let size = if day == 4 {
+        (size as f64 * (scale as f64).sqrt()) as usize
+    } else {
+        size * scale
+    };
+    aoc_gen::generate(day, 1, size).unwrap().input
+}
+
+fn day_01(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day 01");
+    group.sample_size(10);
+    for scale in SCALES {
+        let input = generated_input(1, scale);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("find_total_distance", scale),
+            &input,
+            |b, input| {
+                b.iter(|| day_01_part1::find_total_distance::<i64, _>(black_box(input.as_bytes())))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("find_similarity_score", scale),
+            &input,
+            |b, input| {
+                b.iter(|| {
+                    day_01_part2::find_similarity_score::<i64, _>(black_box(input.as_bytes()))
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn day_02(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day 02");
+    group.sample_size(10);
+    for scale in SCALES {
+        let reports = day_02_part2::parse_reports(generated_input(2, scale).as_bytes()).unwrap();
+        group.throughput(Throughput::Elements(reports.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new(
+                "determine_if_removing_any_one_element_makes_report_safe",
+                scale,
+            ),
+            &reports,
+            |b, reports| {
+                b.iter(|| {
+                    reports
+                        .iter()
+                        .filter(|r| {
+                            day_02_part2::determine_if_removing_any_one_element_makes_report_safe(
+                                black_box(r),
+                            )
+                        })
+                        .count()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn day_03(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day 03");
+    group.sample_size(10);
+    for scale in SCALES {
+        let memory = generated_input(3, scale);
+        group.throughput(Throughput::Bytes(memory.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("sum_multiplications", scale),
+            &memory,
+            |b, memory| b.iter(|| day_03_part01::sum_multiplications(black_box(memory))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sum_multiplications_with_conditionals", scale),
+            &memory,
+            |b, memory| b.iter(|| day_03_part02::sum_multiplications(true, black_box(memory))),
+        );
+    }
+    group.finish();
+}
+
+fn day_04(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day 04");
+    group.sample_size(10);
+    for scale in SCALES {
+        let puzzle = build_puzzle_map(generated_input(4, scale).as_bytes()).unwrap();
+        let cells: Vec<(usize, usize)> = (0..puzzle.len())
+            .flat_map(|y| (0..puzzle[0].len()).map(move |x| (x, y)))
+            .collect();
+        group.throughput(Throughput::Elements(cells.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("search_from_coord", scale),
+            &puzzle,
+            |b, puzzle| {
+                b.iter(|| {
+                    cells
+                        .iter()
+                        .filter(|(x, y)| puzzle[*y][*x] == 'X')
+                        .map(|(x, y)| day_04_part01::search_from_coord(black_box(puzzle), *x, *y).0)
+                        .sum::<usize>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("search_from_coord_cross", scale),
+            &puzzle,
+            |b, puzzle| {
+                b.iter(|| {
+                    let mut coords = Vec::new();
+                    cells
+                        .iter()
+                        .filter(|(x, y)| puzzle[*y][*x] == 'A')
+                        .filter(|(x, y)| {
+                            day_04_part02::search_from_coord(black_box(puzzle), *x, *y, &mut coords)
+                        })
+                        .count()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, day_01, day_02, day_03, day_04);
+criterion_main!(benches);
@@ -0,0 +1,60 @@
+use std::{fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+/// Accepted answers, keyed by day, part and a hash of the input they were
+/// computed from, as stored in `answers.toml`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AnswerBook {
+    #[serde(default, rename = "answer")]
+    answers: Vec<RecordedAnswer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub value: String,
+}
+
+impl AnswerBook {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| format!("could not parse {}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents =
+            toml::to_string(self).map_err(|e| format!("could not serialize answers: {}", e))?;
+        fs::write(path, contents).map_err(|e| format!("could not write {}: {}", path.display(), e))
+    }
+
+    pub fn lookup(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input == input)
+            .map(|a| a.value.as_str())
+    }
+
+    pub fn record(&mut self, answer: RecordedAnswer) {
+        self.answers
+            .retain(|a| !(a.day == answer.day && a.part == answer.part && a.input == answer.input));
+        self.answers.push(answer);
+        self.answers
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+}
+
+/// 64-bit FNV-1a of the raw input bytes. Stable across platforms and Rust
+/// releases, unlike `DefaultHasher`, so recorded hashes stay valid.
+pub fn input_hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("fnv1a64:{:016x}", hash)
+}
@@ -0,0 +1,139 @@
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Input, Solver};
+
+use crate::{alloc_counter::allocations, inputs::find_input, registry};
+
+struct Samples {
+    durations: Vec<Duration>,
+    allocations: usize,
+}
+
+impl Samples {
+    fn with_capacity(iterations: usize) -> Self {
+        Self {
+            durations: Vec::with_capacity(iterations),
+            allocations: 0,
+        }
+    }
+
+    fn record<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let allocations_before = allocations();
+        let start = Instant::now();
+        let result = f();
+        self.durations.push(start.elapsed());
+        self.allocations += allocations() - allocations_before;
+        result
+    }
+
+    fn percentile(&self, p: f64) -> Duration {
+        let mut sorted = self.durations.clone();
+        sorted.sort_unstable();
+        let rank = (p * sorted.len() as f64).ceil() as usize;
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    }
+
+    fn allocations_per_run(&self) -> usize {
+        self.allocations / self.durations.len()
+    }
+}
+
+/// Times the parse and compute phases of every solver (or only those for
+/// `day`) separately over `iterations` runs on its puzzle input.
+pub fn bench(day: Option<u8>, iterations: usize, inputs: &Path) -> Result<bool, String> {
+    if iterations == 0 {
+        return Err("--iterations must be at least 1".to_string());
+    }
+
+    let solvers: Vec<Box<dyn Solver>> = registry::solvers()
+        .into_iter()
+        .filter(|s| day.is_none_or(|day| s.day() == day))
+        .collect();
+    if solvers.is_empty() {
+        return Err(format!(
+            "no solvers registered for day {}",
+            day.unwrap_or(0)
+        ));
+    }
+
+    println!(
+        "{:<4} {:<4} {:<8} {:>10} {:>10} {:>10} {:>11}",
+        "day", "part", "phase", "min", "median", "p95", "allocs/run"
+    );
+
+    let mut all_ok = true;
+    for solver in solvers {
+        let (day, part) = (solver.day(), solver.part());
+        let Some(path) = find_input(inputs, day, part) else {
+            println!("{:<4} {:<4} no input in {}", day, part, inputs.display());
+            continue;
+        };
+        let bytes = Input::open(&path).map_err(|e| format!("could not read {}", e))?;
+
+        match bench_solver(solver.as_ref(), &bytes, iterations) {
+            Ok((parse, compute)) => {
+                print_samples(day, part, "parse", &parse);
+                print_samples(day, part, "compute", &compute);
+            }
+            Err(e) => {
+                all_ok = false;
+                println!("{:<4} {:<4} failed: {}", day, part, e);
+            }
+        }
+    }
+
+    Ok(all_ok)
+}
+
+fn bench_solver(
+    solver: &dyn Solver,
+    input: &[u8],
+    iterations: usize,
+) -> Result<(Samples, Samples), String> {
+    // Warm up caches and surface errors before timing anything.
+    let parsed = solver.parse_bytes(input).map_err(|e| e.to_string())?;
+    solver.compute(parsed.as_ref()).map_err(|e| e.to_string())?;
+    drop(parsed);
+
+    let mut parse = Samples::with_capacity(iterations);
+    let mut compute = Samples::with_capacity(iterations);
+    for _ in 0..iterations {
+        let parsed = parse
+            .record(|| solver.parse_bytes(input))
+            .map_err(|e| e.to_string())?;
+        compute
+            .record(|| solver.compute(parsed.as_ref()))
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok((parse, compute))
+}
+
+fn print_samples(day: u8, part: u8, phase: &str, samples: &Samples) {
+    println!(
+        "{:<4} {:<4} {:<8} {:>10} {:>10} {:>10} {:>11}",
+        day,
+        part,
+        phase,
+        format_duration(samples.percentile(0.0)),
+        format_duration(samples.percentile(0.5)),
+        format_duration(samples.percentile(0.95)),
+        samples.allocations_per_run()
+    );
+}
+
+fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
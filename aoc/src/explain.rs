@@ -0,0 +1,153 @@
+use std::{io::BufRead, str::FromStr};
+
+use aoc_common::SolveError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Csv,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "unknown format '{}' (expected table, csv or json)",
+                s
+            )),
+        }
+    }
+}
+
+/// Prints the work behind a day 1 answer. The table is followed by the answer
+/// on stdout; for CSV and JSON the answer goes to stderr so stdout stays
+/// machine readable.
+pub fn explain<R: BufRead>(
+    day: u8,
+    part: u8,
+    reader: R,
+    format: Format,
+    input_name: &str,
+) -> Result<(), String> {
+    let failed = |e: SolveError| {
+        format!(
+            "day {} part {} failed: {}",
+            day,
+            part,
+            e.with_file(input_name)
+        )
+    };
+
+    let (headers, rows, answer): (&[&str], Vec<Vec<i64>>, i64) = match (day, part) {
+        (1, 1) => {
+            let (a_list, b_list) =
+                day_01_part1::parse_location_lists::<i64, _>(reader).map_err(failed)?;
+            let pairs = day_01_part1::explain_total_distance(&a_list, &b_list).map_err(failed)?;
+            let answer = day_01_part1::total_distance(&a_list, &b_list).map_err(failed)?;
+            let rows = pairs
+                .iter()
+                .map(|p| vec![p.left, p.right, p.distance])
+                .collect();
+            (&["left", "right", "distance"], rows, answer)
+        }
+        (1, 2) => {
+            let (left_list, right_counts) =
+                day_01_part2::parse_left_list_and_right_counts::<i64, _>(reader).map_err(failed)?;
+            let scores = day_01_part2::explain_similarity_score(&left_list, &right_counts)
+                .map_err(failed)?;
+            let answer =
+                day_01_part2::similarity_score(&left_list, &right_counts).map_err(failed)?;
+            let rows = scores
+                .iter()
+                .map(|s| vec![s.left, s.occurrences as i64, s.score])
+                .collect();
+            (&["left", "occurrences", "score"], rows, answer)
+        }
+        _ => {
+            return Err(format!(
+                "--explain is not supported for day {} part {}",
+                day, part
+            ))
+        }
+    };
+
+    print!("{}", render(format, headers, &rows));
+    match format {
+        Format::Table => println!("day {} part {}: {}", day, part, answer),
+        Format::Csv | Format::Json => eprintln!("day {} part {}: {}", day, part, answer),
+    }
+
+    Ok(())
+}
+
+fn render(format: Format, headers: &[&str], rows: &[Vec<i64>]) -> String {
+    match format {
+        Format::Table => render_table(headers, rows),
+        Format::Csv => std::iter::once(headers.join(","))
+            .chain(rows.iter().map(|row| {
+                let cells: Vec<String> = row.iter().map(i64::to_string).collect();
+                cells.join(",")
+            }))
+            .map(|line| line + "\n")
+            .collect(),
+        Format::Json => {
+            let objects: Vec<String> = rows
+                .iter()
+                .map(|row| {
+                    let fields: Vec<String> = headers
+                        .iter()
+                        .zip(row)
+                        .map(|(header, value)| format!("\"{}\": {}", header, value))
+                        .collect();
+                    format!("  {{{}}}", fields.join(", "))
+                })
+                .collect();
+            if objects.is_empty() {
+                "[]\n".to_string()
+            } else {
+                format!("[\n{}\n]\n", objects.join(",\n"))
+            }
+        }
+    }
+}
+
+fn render_table(headers: &[&str], rows: &[Vec<i64>]) -> String {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(i64::to_string).collect())
+        .collect();
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, header)| {
+            cells
+                .iter()
+                .map(|row| row[i].len())
+                .chain(std::iter::once(header.len()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let line = |values: Vec<String>| {
+        let padded: Vec<String> = values
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{:>width$}", value, width = width))
+            .collect();
+        padded.join("  ") + "\n"
+    };
+
+    let mut table = line(headers.iter().map(|h| h.to_string()).collect());
+    table += &line(widths.iter().map(|w| "-".repeat(*w)).collect());
+    for row in cells {
+        table += &line(row);
+    }
+    table
+}
@@ -0,0 +1,30 @@
+use std::io::BufRead;
+
+use aoc_common::{numbered_lines, parse_into_number_pair};
+use day_01_part1::StreamingLists;
+
+/// Reads day 1 pairs as they arrive and prints both running answers after
+/// every line, so a live feed can be piped in.
+pub fn follow<R: BufRead>(day: u8, reader: R, input_name: &str) -> Result<(), String> {
+    if day != 1 {
+        return Err(format!("--follow is not supported for day {}", day));
+    }
+
+    let mut lists = StreamingLists::new();
+    for (number, pair) in numbered_lines(reader)
+        .map(parse_into_number_pair)
+        .enumerate()
+    {
+        let (a, b) =
+            pair.map_err(|e| format!("day {} failed: {}", day, e.with_file(input_name)))?;
+        lists.push(a, b);
+        println!(
+            "line {}: total distance {}, similarity score {}",
+            number + 1,
+            lists.total_distance(),
+            lists.similarity_score()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use std::path::{Path, PathBuf};
+
+/// Finds the puzzle input for a day and part in `inputs`, preferring a
+/// part-specific `day-03-part2.txt` over the shared `day-03.txt`.
+pub fn find_input(inputs: &Path, day: u8, part: u8) -> Option<PathBuf> {
+    [
+        format!("day-{:02}-part{}.txt", day, part),
+        format!("day-{:02}.txt", day),
+    ]
+    .into_iter()
+    .map(|name| inputs.join(name))
+    .find(|path| path.is_file())
+}
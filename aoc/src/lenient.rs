@@ -0,0 +1,45 @@
+use aoc_common::{reports::parse_report_bytes_leniently, SafetyPolicy};
+
+/// Solves day 2 over the lines that parse, then lists every line that was
+/// skipped and why.
+pub fn lenient(
+    day: u8,
+    part: u8,
+    input: &[u8],
+    policy: &SafetyPolicy,
+    input_name: &str,
+) -> Result<(), String> {
+    let count: fn(&[Vec<i32>], &SafetyPolicy) -> i32 = match (day, part) {
+        (2, 1) => day_02_part1::count_safe_reports_under,
+        (2, 2) => day_02_part2::count_safe_reports_under,
+        _ => {
+            return Err(format!(
+                "--lenient is not supported for day {} part {}",
+                day, part
+            ))
+        }
+    };
+    let parsed = parse_report_bytes_leniently(input);
+
+    println!(
+        "day {} part {}: {}",
+        day,
+        part,
+        count(&parsed.reports, policy)
+    );
+    println!(
+        "{} reports read, {} malformed lines skipped, {} blank lines skipped",
+        parsed.reports.len(),
+        parsed.malformed.len(),
+        parsed.blank_lines.len()
+    );
+    for e in parsed.malformed {
+        println!("  {}", e.with_file(input_name));
+    }
+    if !parsed.blank_lines.is_empty() {
+        let lines: Vec<String> = parsed.blank_lines.iter().map(usize::to_string).collect();
+        println!("  blank lines: {}", lines.join(","));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,475 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use answers::{input_hash, AnswerBook, RecordedAnswer};
+use aoc_common::Input;
+use options::Options;
+
+mod alloc_counter;
+mod answers;
+mod bench;
+mod explain;
+mod follow;
+mod inputs;
+mod lenient;
+mod options;
+mod policy;
+mod registry;
+mod removals;
+mod stats;
+mod verdicts;
+mod verify;
+
+#[global_allocator]
+static ALLOCATOR: alloc_counter::CountingAllocator = alloc_counter::CountingAllocator;
+
+const USAGE: &str = "usage:
+    aoc run <day> <part> [--input <path>] [solver options]
+    aoc record <day> <part> [--input <path>] [--answers <path>] [solver options]
+    aoc verify [--answers <path>] [--inputs <dir>]
+    aoc bench [<day>] [--iterations <n>] [--inputs <dir>]
+    aoc gen <day> [--seed <n>] [--size <n>] [--output <path>]
+    aoc list
+
+solver options:
+    day 1: --parallel[=<threads>]
+    day 1 part 1: --external-sort [--run-len=<n>] [--temp-dir=<path>]
+    day 1 (run only): --follow, --explain[=table|csv|json]
+    day 2 (run only): [--policy=<file>] [--min-step=<n>] [--max-step=<n>]
+                      [--direction=increasing|decreasing|either|non-strict] [--max-drift=<n>]
+    day 2 (run only): --report=unsafe|all, --stats=csv|jsonl, --lenient
+    day 2 part 2 (run only): --max-removals=<k>";
+
+const DEFAULT_ANSWERS: &str = "answers.toml";
+const DEFAULT_INPUTS: &str = "inputs";
+const DEFAULT_ITERATIONS: &str = "20";
+
+#[derive(Debug)]
+enum Command {
+    Run {
+        day: u8,
+        part: u8,
+        input: Option<String>,
+        options: Options,
+    },
+    Record {
+        day: u8,
+        part: u8,
+        input: Option<String>,
+        answers: PathBuf,
+        options: Options,
+    },
+    Verify {
+        answers: PathBuf,
+        inputs: PathBuf,
+    },
+    Bench {
+        day: Option<u8>,
+        iterations: usize,
+        inputs: PathBuf,
+    },
+    Gen {
+        day: u8,
+        seed: u64,
+        size: Option<usize>,
+        output: Option<PathBuf>,
+    },
+    List,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match parse_args(&args).and_then(execute) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let (command, rest) = args.split_first().ok_or_else(|| USAGE.to_string())?;
+    match command.as_str() {
+        "run" => {
+            let allowed = &["--input"];
+            let (rest, options) = split_solver_options(rest, allowed)?;
+            let (day, part, mut flags) = parse_day_and_part(&rest, allowed)?;
+            Ok(Command::Run {
+                day,
+                part,
+                input: flags.take("--input"),
+                options,
+            })
+        }
+        "record" => {
+            let allowed = &["--input", "--answers"];
+            let (rest, options) = split_solver_options(rest, allowed)?;
+            let (day, part, mut flags) = parse_day_and_part(&rest, allowed)?;
+            Ok(Command::Record {
+                day,
+                part,
+                input: flags.take("--input"),
+                answers: flags.take_or("--answers", DEFAULT_ANSWERS).into(),
+                options,
+            })
+        }
+        "verify" => {
+            let (positional, mut flags) = parse_flags(rest, &["--answers", "--inputs"])?;
+            if !positional.is_empty() {
+                return Err(USAGE.to_string());
+            }
+            Ok(Command::Verify {
+                answers: flags.take_or("--answers", DEFAULT_ANSWERS).into(),
+                inputs: flags.take_or("--inputs", DEFAULT_INPUTS).into(),
+            })
+        }
+        "bench" => {
+            let (positional, mut flags) = parse_flags(rest, &["--iterations", "--inputs"])?;
+            let day = match positional.as_slice() {
+                [] => None,
+                [day] => Some(parse_number(day)?),
+                _ => return Err(USAGE.to_string()),
+            };
+            let iterations = flags.take_or("--iterations", DEFAULT_ITERATIONS);
+            Ok(Command::Bench {
+                day,
+                iterations: iterations
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a valid iteration count", iterations))?,
+                inputs: flags.take_or("--inputs", DEFAULT_INPUTS).into(),
+            })
+        }
+        "gen" => {
+            let (positional, mut flags) = parse_flags(rest, &["--seed", "--size", "--output"])?;
+            let day = match positional.as_slice() {
+                [day] => parse_number(day)?,
+                _ => return Err(USAGE.to_string()),
+            };
+            let seed = flags.take_or("--seed", "1");
+            Ok(Command::Gen {
+                day,
+                seed: seed
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a valid seed", seed))?,
+                size: flags
+                    .take("--size")
+                    .map(|size| {
+                        size.parse()
+                            .map_err(|_| format!("'{}' is not a valid size", size))
+                    })
+                    .transpose()?,
+                output: flags.take("--output").map(PathBuf::from),
+            })
+        }
+        "list" if rest.is_empty() => Ok(Command::List),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+struct Flags(Vec<(String, String)>);
+
+impl Flags {
+    fn take(&mut self, name: &str) -> Option<String> {
+        let index = self.0.iter().position(|(n, _)| n == name)?;
+        Some(self.0.remove(index).1)
+    }
+
+    fn take_or(&mut self, name: &str, default: &str) -> String {
+        self.take(name).unwrap_or_else(|| default.to_string())
+    }
+}
+
+fn parse_flags(args: &[String], allowed: &[&str]) -> Result<(Vec<String>, Flags), String> {
+    let mut positional = Vec::new();
+    let mut flags = Vec::new();
+    let mut rest = args;
+    while let [arg, tail @ ..] = rest {
+        rest = tail;
+        if !arg.starts_with("--") {
+            positional.push(arg.clone());
+            continue;
+        }
+        if !allowed.contains(&arg.as_str()) {
+            return Err(format!("unexpected argument '{}'\n{}", arg, USAGE));
+        }
+        match rest {
+            [value, tail @ ..] => {
+                flags.push((arg.clone(), value.clone()));
+                rest = tail;
+            }
+            [] => return Err(format!("'{}' needs a value\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok((positional, Flags(flags)))
+}
+
+/// Separates the `--key[=value]` arguments meant for the solver from the
+/// runner's own flags, which always take their value as the next argument.
+fn split_solver_options(
+    args: &[String],
+    allowed: &[&str],
+) -> Result<(Vec<String>, Options), String> {
+    let mut runner_args = Vec::new();
+    let mut solver_args = Vec::new();
+    let mut rest = args;
+    while let [arg, tail @ ..] = rest {
+        rest = tail;
+        if !arg.starts_with("--") {
+            runner_args.push(arg.clone());
+        } else if allowed.contains(&arg.as_str()) {
+            runner_args.push(arg.clone());
+            if let [value, tail @ ..] = rest {
+                runner_args.push(value.clone());
+                rest = tail;
+            }
+        } else {
+            solver_args.push(arg.clone());
+        }
+    }
+
+    Ok((runner_args, Options::parse(&solver_args)?))
+}
+
+fn parse_day_and_part(args: &[String], allowed: &[&str]) -> Result<(u8, u8, Flags), String> {
+    let (positional, flags) = parse_flags(args, allowed)?;
+    match positional.as_slice() {
+        [day, part] => Ok((parse_number(day)?, parse_number(part)?, flags)),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn parse_number(value: &str) -> Result<u8, String> {
+    value
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid day or part number", value))
+}
+
+fn execute(command: Command) -> Result<bool, String> {
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            options,
+        } => run(day, part, input.as_deref(), options).map(|_| true),
+        Command::Record {
+            day,
+            part,
+            input,
+            answers,
+            options,
+        } => record(day, part, input.as_deref(), &answers, options).map(|_| true),
+        Command::Verify { answers, inputs } => verify::verify(&answers, &inputs),
+        Command::Bench {
+            day,
+            iterations,
+            inputs,
+        } => bench::bench(day, iterations, &inputs),
+        Command::Gen {
+            day,
+            seed,
+            size,
+            output,
+        } => gen(day, seed, size, output.as_deref()).map(|_| true),
+        Command::List => {
+            for solver in registry::solvers() {
+                println!(
+                    "day {} part {}: {}",
+                    solver.day(),
+                    solver.part(),
+                    solver.name()
+                );
+            }
+            Ok(true)
+        }
+    }
+}
+
+/// What `aoc run` does with the input besides solving it normally.
+enum RunMode {
+    Solve(Box<dyn aoc_common::Solver>),
+    Follow,
+    Explain(explain::Format),
+    Removals(usize, aoc_common::SafetyPolicy),
+    Report(verdicts::Shown, aoc_common::SafetyPolicy),
+    Stats(stats::Format, aoc_common::SafetyPolicy),
+    Lenient(aoc_common::SafetyPolicy),
+}
+
+fn run_mode(day: u8, part: u8, mut options: Options) -> Result<RunMode, String> {
+    if options.flag("follow")? {
+        options.finish()?;
+        return Ok(RunMode::Follow);
+    }
+    if let Some(format) = options.value_or("explain", "table")? {
+        options.finish()?;
+        return Ok(RunMode::Explain(format));
+    }
+    if let Some(max_removals) = options.value("max-removals")? {
+        let policy = policy::safety_policy(&mut options)?.unwrap_or_default();
+        options.finish()?;
+        return Ok(RunMode::Removals(max_removals, policy));
+    }
+    if let Some(shown) = options.value("report")? {
+        let policy = policy::safety_policy(&mut options)?.unwrap_or_default();
+        options.finish()?;
+        return Ok(RunMode::Report(shown, policy));
+    }
+    if let Some(format) = options.value("stats")? {
+        let policy = policy::safety_policy(&mut options)?.unwrap_or_default();
+        options.finish()?;
+        return Ok(RunMode::Stats(format, policy));
+    }
+    if options.flag("lenient")? {
+        let policy = policy::safety_policy(&mut options)?.unwrap_or_default();
+        options.finish()?;
+        return Ok(RunMode::Lenient(policy));
+    }
+    registry::configure_solver(day, part, options).map(RunMode::Solve)
+}
+
+fn run(day: u8, part: u8, input: Option<&str>, options: Options) -> Result<(), String> {
+    let mode = run_mode(day, part, options)?;
+
+    let solver = match mode {
+        RunMode::Solve(solver) => solver,
+        // Following reads the input as it arrives, so it can't wait for all
+        // of it to be loaded.
+        RunMode::Follow => return follow::follow(day, open_reader(input)?, input_name(input)),
+        RunMode::Explain(format) => {
+            let bytes = load_input(input)?;
+            return explain::explain(day, part, bytes.bytes(), format, input_name(input));
+        }
+        RunMode::Removals(max_removals, policy) => {
+            let bytes = load_input(input)?;
+            return removals::removals(day, part, &bytes, max_removals, &policy, input_name(input));
+        }
+        RunMode::Report(shown, policy) => {
+            let bytes = load_input(input)?;
+            return verdicts::report(day, part, &bytes, shown, &policy, input_name(input));
+        }
+        RunMode::Stats(format, policy) => {
+            let bytes = load_input(input)?;
+            return stats::stats(day, part, &bytes, format, &policy, input_name(input));
+        }
+        RunMode::Lenient(policy) => {
+            let bytes = load_input(input)?;
+            return lenient::lenient(day, part, &bytes, &policy, input_name(input));
+        }
+    };
+
+    // A streaming solver (such as the external sort) exists to handle input
+    // that doesn't fit in memory, so it mustn't be loaded first.
+    let answer = if solver.streams_input() {
+        solver.solve(&mut open_reader(input)?)
+    } else {
+        solver.solve_bytes(&load_input(input)?)
+    };
+    let answer = answer.map_err(|e| {
+        format!(
+            "day {} part {} failed: {}",
+            day,
+            part,
+            e.with_file(input_name(input))
+        )
+    })?;
+    println!("day {} part {}: {}", day, part, answer);
+
+    Ok(())
+}
+
+/// The input as a reader over `input`'s file, or over stdin.
+fn open_reader(input: Option<&str>) -> Result<Box<dyn BufRead>, String> {
+    match input {
+        Some(path) => {
+            let f = File::open(path).map_err(|e| format!("could not open {}: {}", path, e))?;
+            Ok(Box::new(BufReader::new(f)))
+        }
+        None => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+/// The whole input, memory-mapped when `input` names a regular file and read
+/// from stdin otherwise.
+fn load_input(input: Option<&str>) -> Result<Input, String> {
+    match input {
+        Some(path) => Input::open(path).map_err(|e| format!("could not read {}", e)),
+        None => Input::stdin().map_err(|e| format!("could not read <stdin>: {}", e)),
+    }
+}
+
+fn record(
+    day: u8,
+    part: u8,
+    input: Option<&str>,
+    answers: &Path,
+    mut options: Options,
+) -> Result<(), String> {
+    // Recorded answers are checked by `verify` against the puzzle's rules.
+    if day == 2 && policy::safety_policy(&mut options)?.is_some() {
+        return Err("safety policies can't be recorded, only run".to_string());
+    }
+    let solver = registry::configure_solver(day, part, options)?;
+
+    let bytes = load_input(input)?;
+    let answer = solver.solve_bytes(&bytes).map_err(|e| {
+        format!(
+            "day {} part {} failed: {}",
+            day,
+            part,
+            e.with_file(input_name(input))
+        )
+    })?;
+
+    let mut book = AnswerBook::load(answers)?;
+    book.record(RecordedAnswer {
+        day,
+        part,
+        input: input_hash(&bytes),
+        value: answer.to_string(),
+    });
+    book.save(answers)?;
+    println!(
+        "day {} part {}: {} (recorded in {})",
+        day,
+        part,
+        answer,
+        answers.display()
+    );
+
+    Ok(())
+}
+
+/// Writes a generated input to `output` (or stdout) and its expected answers
+/// to stderr, so the input can be piped straight into `aoc run`.
+fn gen(day: u8, seed: u64, size: Option<usize>, output: Option<&Path>) -> Result<(), String> {
+    let size = size
+        .or_else(|| aoc_gen::puzzle_size(day))
+        .ok_or_else(|| format!("no generator for day {}", day))?;
+    let generated = aoc_gen::generate(day, seed, size)
+        .ok_or_else(|| format!("no generator for day {}", day))?;
+
+    match output {
+        Some(path) => fs::write(path, &generated.input)
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))?,
+        None => io::stdout()
+            .write_all(generated.input.as_bytes())
+            .map_err(|e| format!("could not write to stdout: {}", e))?,
+    }
+    eprintln!("day {} part 1: {}", day, generated.part1);
+    eprintln!("day {} part 2: {}", day, generated.part2);
+
+    Ok(())
+}
+
+fn input_name(input: Option<&str>) -> &str {
+    input.unwrap_or("<stdin>")
+}
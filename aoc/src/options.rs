@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+/// Solver-specific `--key` / `--key=value` arguments. Each option is consumed
+/// by whichever solver understands it; anything left over is an error.
+#[derive(Debug, Default)]
+pub struct Options(Vec<(String, Option<String>)>);
+
+impl Options {
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        args.iter()
+            .map(|arg| {
+                let option = arg
+                    .strip_prefix("--")
+                    .filter(|option| !option.is_empty())
+                    .ok_or_else(|| format!("unexpected argument '{}'", arg))?;
+                Ok(match option.split_once('=') {
+                    Some((key, value)) => (key.to_string(), Some(value.to_string())),
+                    None => (option.to_string(), None),
+                })
+            })
+            .collect::<Result<_, String>>()
+            .map(Options)
+    }
+
+    pub fn flag(&mut self, key: &str) -> Result<bool, String> {
+        match self.take(key) {
+            Some(None) => Ok(true),
+            Some(Some(_)) => Err(format!("--{} does not take a value", key)),
+            None => Ok(false),
+        }
+    }
+
+    pub fn value<T: FromStr>(&mut self, key: &str) -> Result<Option<T>, String> {
+        match self.take(key) {
+            Some(Some(value)) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| format!("'{}' is not a valid value for --{}", value, key)),
+            Some(None) => Err(format!("--{} needs a value (--{}=<value>)", key, key)),
+            None => Ok(None),
+        }
+    }
+
+    /// Like `value`, but a bare `--key` is read as `--key=<bare>`.
+    pub fn value_or<T: FromStr>(&mut self, key: &str, bare: &str) -> Result<Option<T>, String> {
+        if let Some(index) = self.0.iter().position(|(k, v)| k == key && v.is_none()) {
+            self.0[index].1 = Some(bare.to_string());
+        }
+        self.value(key)
+    }
+
+    /// Fails if any option was not consumed by the selected solver.
+    pub fn finish(self) -> Result<(), String> {
+        match self.0.first() {
+            Some((key, _)) => Err(format!("option --{} is not supported here", key)),
+            None => Ok(()),
+        }
+    }
+
+    fn take(&mut self, key: &str) -> Option<Option<String>> {
+        let index = self.0.iter().position(|(k, _)| k == key)?;
+        Some(self.0.remove(index).1)
+    }
+}
@@ -0,0 +1,72 @@
+use std::fs;
+
+use aoc_common::{safety::Direction, SafetyPolicy};
+use serde::Deserialize;
+
+use crate::options::Options;
+
+/// A day 2 safety policy as written in a `--policy` file. Anything left out
+/// keeps the puzzle's rule:
+///
+/// ```toml
+/// min_step = 1
+/// max_step = 5
+/// direction = "increasing"
+/// max_drift = 20
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyFile {
+    min_step: Option<u32>,
+    max_step: Option<u32>,
+    direction: Option<String>,
+    max_drift: Option<u64>,
+}
+
+/// The policy picked by `--policy=<file>`, with `--min-step`, `--max-step`,
+/// `--direction` and `--max-drift` applied on top. `None` when none of them
+/// were given.
+pub fn safety_policy(options: &mut Options) -> Result<Option<SafetyPolicy>, String> {
+    let file = match options.value::<String>("policy")? {
+        Some(path) => Some(load(&path)?),
+        None => None,
+    };
+    let flags = PolicyFile {
+        min_step: options.value("min-step")?,
+        max_step: options.value("max-step")?,
+        direction: options.value("direction")?,
+        max_drift: options.value("max-drift")?,
+    };
+    if file.is_none()
+        && flags.min_step.is_none()
+        && flags.max_step.is_none()
+        && flags.direction.is_none()
+        && flags.max_drift.is_none()
+    {
+        return Ok(None);
+    }
+
+    let mut policy = SafetyPolicy::default();
+    for settings in file.into_iter().chain([flags]) {
+        policy.min_step = settings.min_step.unwrap_or(policy.min_step);
+        policy.max_step = settings.max_step.unwrap_or(policy.max_step);
+        if let Some(direction) = settings.direction {
+            policy.direction = direction.parse::<Direction>()?;
+        }
+        policy.max_drift = settings.max_drift.or(policy.max_drift);
+    }
+    if policy.min_step > policy.max_step {
+        return Err(format!(
+            "minimum step {} is larger than maximum step {}",
+            policy.min_step, policy.max_step
+        ));
+    }
+
+    Ok(Some(policy))
+}
+
+fn load(path: &str) -> Result<PolicyFile, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))?;
+    toml::from_str(&contents).map_err(|e| format!("could not parse {}: {}", path, e))
+}
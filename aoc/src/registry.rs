@@ -0,0 +1,88 @@
+use aoc_common::Solver;
+
+use crate::{options::Options, policy::safety_policy};
+
+pub fn solvers() -> Vec<Box<dyn Solver>> {
+    vec![
+        Box::new(day_01_part1::TotalDistance),
+        Box::new(day_01_part2::SimilarityScore),
+        Box::new(day_02_part1::SafeReports),
+        Box::new(day_02_part2::DampenedSafeReports),
+        Box::new(day_03_part01::Multiplications),
+        Box::new(day_03_part02::ConditionalMultiplications),
+        Box::new(day_04_part01::XmasSearch),
+        Box::new(day_04_part02::CrossMasSearch),
+    ]
+}
+
+pub fn find_solver(day: u8, part: u8) -> Option<Box<dyn Solver>> {
+    solvers()
+        .into_iter()
+        .find(|s| s.day() == day && s.part() == part)
+}
+
+/// Looks up a solver and lets it consume the options it understands.
+pub fn configure_solver(
+    day: u8,
+    part: u8,
+    mut options: Options,
+) -> Result<Box<dyn Solver>, String> {
+    let solver = match (day, part) {
+        (1, 1) => day_01_part1(&mut options)?,
+        (1, 2) => day_01_part2(&mut options)?,
+        (2, 1) => day_02_part1(&mut options)?,
+        (2, 2) => day_02_part2(&mut options)?,
+        _ => find_solver(day, part)
+            .ok_or_else(|| format!("no solver registered for day {} part {}", day, part))?,
+    };
+    options.finish()?;
+
+    Ok(solver)
+}
+
+fn day_01_part1(options: &mut Options) -> Result<Box<dyn Solver>, String> {
+    if let Some(threads) = parallel_threads(options)? {
+        return Ok(Box::new(day_01_part1::ParallelTotalDistance::new(threads)));
+    }
+    if !options.flag("external-sort")? {
+        return Ok(Box::new(day_01_part1::TotalDistance));
+    }
+
+    let mut solver = day_01_part1::ExternalTotalDistance::new(
+        options
+            .value("run-len")?
+            .unwrap_or(day_01_part1::DEFAULT_RUN_LEN),
+    );
+    if let Some(dir) = options.value::<String>("temp-dir")? {
+        solver = solver.in_dir(dir);
+    }
+
+    Ok(Box::new(solver))
+}
+
+fn day_01_part2(options: &mut Options) -> Result<Box<dyn Solver>, String> {
+    Ok(match parallel_threads(options)? {
+        Some(threads) => Box::new(day_01_part2::ParallelSimilarityScore::new(threads)),
+        None => Box::new(day_01_part2::SimilarityScore),
+    })
+}
+
+fn day_02_part1(options: &mut Options) -> Result<Box<dyn Solver>, String> {
+    Ok(match safety_policy(options)? {
+        Some(policy) => Box::new(day_02_part1::PolicySafeReports::new(policy)),
+        None => Box::new(day_02_part1::SafeReports),
+    })
+}
+
+fn day_02_part2(options: &mut Options) -> Result<Box<dyn Solver>, String> {
+    Ok(match safety_policy(options)? {
+        Some(policy) => Box::new(day_02_part2::PolicyDampenedSafeReports::new(policy)),
+        None => Box::new(day_02_part2::DampenedSafeReports),
+    })
+}
+
+/// `--parallel` uses every core, `--parallel=<n>` uses `n` threads (but no
+/// more than there are cores).
+fn parallel_threads(options: &mut Options) -> Result<Option<usize>, String> {
+    options.value_or("parallel", "0")
+}
@@ -0,0 +1,59 @@
+use aoc_common::{reports::parse_report_bytes, SafetyPolicy};
+use day_02_part2::{fewest_removals, RemovalHistogram};
+
+/// Prints which levels each day 2 report needs removed to be safe, then how
+/// many reports need each number of removals and how many are made safe by at
+/// most `max_removals` of them.
+pub fn removals(
+    day: u8,
+    part: u8,
+    input: &[u8],
+    max_removals: usize,
+    policy: &SafetyPolicy,
+    input_name: &str,
+) -> Result<(), String> {
+    if (day, part) != (2, 2) {
+        return Err(format!(
+            "--max-removals is not supported for day {} part {}",
+            day, part
+        ));
+    }
+    let reports = parse_report_bytes(input).map_err(|e| {
+        format!(
+            "day {} part {} failed: {}",
+            day,
+            part,
+            e.with_file(input_name)
+        )
+    })?;
+
+    let mut histogram = RemovalHistogram::new(max_removals);
+    for (i, report) in reports.iter().enumerate() {
+        let dropped = fewest_removals(report, policy);
+        if !dropped.is_empty() {
+            let indices: Vec<String> = dropped.iter().map(usize::to_string).collect();
+            println!(
+                "line {}: remove {} (indices {})",
+                i + 1,
+                dropped.len(),
+                indices.join(",")
+            );
+        }
+        histogram.add(dropped.len());
+    }
+
+    println!("{:<9} reports", "removals");
+    for (removals, count) in histogram.within.iter().enumerate() {
+        println!("{:<9} {}", removals, count);
+    }
+    println!("{:<9} {}", format!(">{}", max_removals), histogram.beyond);
+    println!(
+        "day {} part {} (at most {} removals): {}",
+        day,
+        part,
+        max_removals,
+        histogram.safe()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use std::{
+    fmt::Display,
+    io::{self, BufWriter, Write},
+    str::FromStr,
+};
+
+use aoc_common::{NumberScanner, SafetyPolicy};
+use day_02_part2::{report_stats, ReportStats};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    JsonLines,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "jsonl" => Ok(Format::JsonLines),
+            _ => Err(format!("unknown format '{}' (expected csv or jsonl)", s)),
+        }
+    }
+}
+
+const COLUMNS: [&str; 11] = [
+    "line",
+    "length",
+    "shape",
+    "min_step",
+    "max_step",
+    "mean_step",
+    "violations",
+    "safe_part1",
+    "safe_part2",
+    "dampened_index",
+    "levels",
+];
+
+/// Streams statistics for each day 2 report to stdout as it is parsed, one
+/// row per input line, then prints the answer to stderr.
+pub fn stats(
+    day: u8,
+    part: u8,
+    input: &[u8],
+    format: Format,
+    policy: &SafetyPolicy,
+    input_name: &str,
+) -> Result<(), String> {
+    let dampened = match (day, part) {
+        (2, 1) => false,
+        (2, 2) => true,
+        _ => {
+            return Err(format!(
+                "--stats is not supported for day {} part {}",
+                day, part
+            ))
+        }
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+    let write_failed = |e: io::Error| format!("could not write statistics: {}", e);
+    if format == Format::Csv {
+        writeln!(out, "{}", COLUMNS.join(",")).map_err(write_failed)?;
+    }
+
+    let mut scanner = NumberScanner::new(input);
+    let mut levels = Vec::new();
+    let mut answer = 0;
+    while let Some(line) = scanner.read_line(&mut levels) {
+        let line = line.map_err(|e| {
+            format!(
+                "day {} part {} failed: {}",
+                day,
+                part,
+                e.with_file(input_name)
+            )
+        })?;
+        let stats = report_stats(&levels, policy);
+        answer += usize::from(if dampened {
+            stats.dampened_safe
+        } else {
+            stats.safe
+        });
+        writeln!(out, "{}", row(format, line, &levels, &stats)).map_err(write_failed)?;
+    }
+    out.flush().map_err(write_failed)?;
+
+    eprintln!("day {} part {}: {}", day, part, answer);
+    Ok(())
+}
+
+fn row(format: Format, line: usize, levels: &[i32], stats: &ReportStats) -> String {
+    let levels: Vec<String> = levels.iter().map(i32::to_string).collect();
+    match format {
+        Format::Csv => [
+            line.to_string(),
+            stats.length.to_string(),
+            stats.shape.to_string(),
+            cell(stats.min_step, ""),
+            cell(stats.max_step, ""),
+            cell(stats.mean_step, ""),
+            stats.violations.to_string(),
+            stats.safe.to_string(),
+            stats.dampened_safe.to_string(),
+            cell(stats.dampened_index, ""),
+            levels.join(" "),
+        ]
+        .join(","),
+        Format::JsonLines => {
+            let values = [
+                line.to_string(),
+                stats.length.to_string(),
+                format!("\"{}\"", stats.shape),
+                cell(stats.min_step, "null"),
+                cell(stats.max_step, "null"),
+                cell(stats.mean_step, "null"),
+                stats.violations.to_string(),
+                stats.safe.to_string(),
+                stats.dampened_safe.to_string(),
+                cell(stats.dampened_index, "null"),
+                format!("[{}]", levels.join(",")),
+            ];
+            let fields: Vec<String> = COLUMNS
+                .iter()
+                .zip(values)
+                .map(|(column, value)| format!("\"{}\": {}", column, value))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        }
+    }
+}
+
+/// `value`, or `missing` when there is none.
+fn cell<T: Display>(value: Option<T>, missing: &str) -> String {
+    value.map_or(missing.to_string(), |v| v.to_string())
+}
@@ -0,0 +1,89 @@
+use std::str::FromStr;
+
+use aoc_common::{reports::parse_report_bytes, safety::Verdict, SafetyPolicy};
+
+/// Which reports `--report` prints a verdict for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shown {
+    Unsafe,
+    All,
+}
+
+impl FromStr for Shown {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unsafe" => Ok(Shown::Unsafe),
+            "all" => Ok(Shown::All),
+            _ => Err(format!("unknown report '{}' (expected unsafe or all)", s)),
+        }
+    }
+}
+
+/// Prints why each day 2 report is or isn't safe under the rule of the part
+/// being solved (with the Problem Dampener for part 2), then the answer.
+pub fn report(
+    day: u8,
+    part: u8,
+    input: &[u8],
+    shown: Shown,
+    policy: &SafetyPolicy,
+    input_name: &str,
+) -> Result<(), String> {
+    let is_safe: fn(&[i32], &SafetyPolicy) -> bool = match (day, part) {
+        (2, 1) => |report, policy| policy.is_safe(report),
+        (2, 2) => day_02_part2::dampened_report_is_safe,
+        _ => {
+            return Err(format!(
+                "--report is not supported for day {} part {}",
+                day, part
+            ))
+        }
+    };
+    let reports = parse_report_bytes(input).map_err(|e| {
+        format!(
+            "day {} part {} failed: {}",
+            day,
+            part,
+            e.with_file(input_name)
+        )
+    })?;
+
+    let mut answer = 0;
+    for (i, report) in reports.iter().enumerate() {
+        let safe = is_safe(report, policy);
+        answer += usize::from(safe);
+        if shown == Shown::All || !safe {
+            println!("line {}: {}", i + 1, describe(policy.verdict(report), safe));
+        }
+    }
+    println!("day {} part {}: {}", day, part, answer);
+
+    Ok(())
+}
+
+/// A report's verdict, led by whether it counts as `safe` in the part being
+/// solved.
+fn describe(verdict: Verdict, safe: bool) -> String {
+    match verdict {
+        Verdict::Safe if safe => "safe".to_string(),
+        // Only an empty report is safe as it stands but not in part 2.
+        Verdict::Safe => "unsafe (no levels for the Problem Dampener to remove)".to_string(),
+        Verdict::Unsafe {
+            at,
+            violation,
+            fixed_by,
+        } => {
+            let fix = match fixed_by {
+                Some(level) => format!("safe without index {}", level),
+                None => "no single removal fixes it".to_string(),
+            };
+            if safe {
+                format!("{} (unsafe at index {}, {})", fix, at, violation)
+            } else {
+                format!("unsafe at index {} ({}), {}", at, violation, fix)
+            }
+        }
+    }
+}
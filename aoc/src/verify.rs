@@ -0,0 +1,127 @@
+use std::path::Path;
+
+use aoc_common::Input;
+
+use crate::{
+    answers::{input_hash, AnswerBook},
+    inputs::find_input,
+    registry,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Missing => "MISSING",
+        }
+    }
+}
+
+struct Row {
+    day: u8,
+    part: u8,
+    status: Status,
+    expected: String,
+    actual: String,
+}
+
+/// Reruns every registered solver on its input from `inputs` and compares the
+/// result with the answer recorded for that exact input. Returns `false` if
+/// any solver produced a different answer or failed.
+pub fn verify(answers: &Path, inputs: &Path) -> Result<bool, String> {
+    let book = AnswerBook::load(answers)?;
+
+    let rows: Vec<Row> = registry::solvers()
+        .iter()
+        .map(|solver| {
+            let (day, part) = (solver.day(), solver.part());
+            let Some(path) = find_input(inputs, day, part) else {
+                return Row {
+                    day,
+                    part,
+                    status: Status::Missing,
+                    expected: "-".to_string(),
+                    actual: "no input".to_string(),
+                };
+            };
+
+            let bytes = match Input::open(&path) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    return Row {
+                        day,
+                        part,
+                        status: Status::Fail,
+                        expected: "-".to_string(),
+                        actual: format!("could not read {}", e),
+                    }
+                }
+            };
+            let expected = book.lookup(day, part, &input_hash(&bytes));
+            let actual = solver.solve_bytes(&bytes).map(|answer| answer.to_string());
+
+            let status = match (&actual, expected) {
+                (Ok(actual), Some(expected)) if actual == expected => Status::Pass,
+                (Ok(_), None) => Status::Missing,
+                _ => Status::Fail,
+            };
+            Row {
+                day,
+                part,
+                status,
+                expected: expected.unwrap_or("-").to_string(),
+                actual: actual
+                    .unwrap_or_else(|e| e.with_file(path.display().to_string()).to_string()),
+            }
+        })
+        .collect();
+
+    print_table(&rows);
+
+    let count = |status| rows.iter().filter(|r| r.status == status).count();
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing)
+    );
+
+    Ok(count(Status::Fail) == 0)
+}
+
+fn print_table(rows: &[Row]) {
+    let expected_width = rows
+        .iter()
+        .map(|r| r.expected.len())
+        .chain(std::iter::once("expected".len()))
+        .max()
+        .unwrap_or(0);
+
+    println!(
+        "{:<4} {:<4} {:<7} {:<width$} actual",
+        "day",
+        "part",
+        "status",
+        "expected",
+        width = expected_width
+    );
+    for row in rows {
+        println!(
+            "{:<4} {:<4} {:<7} {:<width$} {}",
+            row.day,
+            row.part,
+            row.status.label(),
+            row.expected,
+            row.actual,
+            width = expected_width
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! N-column day 1 distances under each metric.
+
+mod common;
+
+use aoc_common::ErrorKind;
+use common::DAY_01;
+use day_01_part1::{find_column_distances, Metric};
+
+const THREE_COLUMNS: &str = "1 2 4\n3 1 6\n";
+
+fn distances(input: &str, metric: Metric) -> (Vec<f64>, f64) {
+    let report = find_column_distances(input.as_bytes(), &metric).unwrap();
+    let columns = report.columns.iter().map(|c| c.distance).collect();
+    (columns, report.total)
+}
+
+#[test]
+fn two_column_l1_is_the_total_distance() {
+    assert_eq!(distances(DAY_01, Metric::L1), (vec![0.0, 11.0], 11.0));
+}
+
+#[test]
+fn two_column_metrics() {
+    assert_eq!(distances(DAY_01, Metric::L2).1, 35f64.sqrt());
+    assert_eq!(distances(DAY_01, Metric::Chebyshev).1, 5.0);
+    assert_eq!(distances(DAY_01, Metric::Spread).1, 11.0);
+}
+
+#[test]
+fn three_column_metrics() {
+    assert_eq!(
+        distances(THREE_COLUMNS, Metric::L1),
+        (vec![0.0, 1.0, 6.0], 7.0)
+    );
+    assert_eq!(
+        distances(THREE_COLUMNS, Metric::L2),
+        (vec![0.0, 1.0, 18f64.sqrt()], 19f64.sqrt())
+    );
+    assert_eq!(
+        distances(THREE_COLUMNS, Metric::Chebyshev),
+        (vec![0.0, 1.0, 3.0], 3.0)
+    );
+    assert_eq!(distances(THREE_COLUMNS, Metric::Spread).1, 7.0);
+}
+
+#[test]
+fn custom_metric_sees_pairs_per_column_and_whole_rows_in_total() {
+    let row_sum = Metric::custom(|row| row.iter().sum::<i64>() as f64);
+    assert_eq!(
+        distances(THREE_COLUMNS, row_sum),
+        (vec![0.0, 7.0, 14.0], 17.0)
+    );
+}
+
+#[test]
+fn report_lists_every_column() {
+    let report = find_column_distances(THREE_COLUMNS.as_bytes(), &Metric::L1).unwrap();
+    assert_eq!(
+        report.to_string(),
+        "metric l1 over 2 rows\n\
+         column 1: min 1 max 3 (reference)\n\
+         column 2: min 1 max 2 distance 1\n\
+         column 3: min 4 max 6 distance 6\n\
+         total: 7"
+    );
+}
+
+#[test]
+fn ragged_rows_are_rejected() {
+    let err = find_column_distances("1 2 3\n4 5\n".as_bytes(), &Metric::L1).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::WrongNumValuesToUnpack {
+            expected: 3,
+            found: 2
+        }
+    ));
+    assert_eq!(err.location().unwrap().line, 2);
+}
+
+#[test]
+fn blank_lines_are_rejected_anywhere() {
+    for (input, line) in [("\n1 2 3\n", 1), ("1 2 3\n\n4 5 6\n", 2)] {
+        let err = find_column_distances(input.as_bytes(), &Metric::L1).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::Parse(_)), "{}", err);
+        assert_eq!(err.location().unwrap().line, line);
+    }
+}
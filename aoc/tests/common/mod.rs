@@ -0,0 +1,71 @@
+//! Fixtures and helpers shared by the integration tests. Each test crate uses
+//! only some of them.
+#![allow(dead_code)]
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use aoc_common::{safety::Direction, SafetyPolicy};
+use proptest::prelude::*;
+
+/// The worked examples from each puzzle description.
+pub const DAY_01: &str = include_str!("../fixtures/day-01.txt");
+pub const DAY_02: &str = include_str!("../fixtures/day-02.txt");
+pub const DAY_03_PART_1: &str = include_str!("../fixtures/day-03-part1.txt");
+pub const DAY_03_PART_2: &str = include_str!("../fixtures/day-03-part2.txt");
+pub const DAY_04: &str = include_str!("../fixtures/day-04.txt");
+
+/// Paths to the same examples, for `--input`.
+pub const DAY_01_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/day-01.txt");
+pub const DAY_02_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/day-02.txt");
+
+/// Runs the `aoc` binary, returning what it wrote to stdout and stderr, or
+/// only stderr if it failed.
+pub fn run_aoc(args: &[&str]) -> Result<(String, String), String> {
+    run_aoc_with_stdin(args, "")
+}
+
+/// `run_aoc` with `stdin` piped in.
+pub fn run_aoc_with_stdin(args: &[&str], stdin: &str) -> Result<(String, String), String> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    if output.status.success() {
+        Ok((String::from_utf8(output.stdout).unwrap(), stderr))
+    } else {
+        Err(stderr)
+    }
+}
+
+/// Day 2 safety policies with small step and drift limits, so that reports of
+/// small levels are often safe under them.
+pub fn policy() -> impl Strategy<Value = SafetyPolicy> {
+    let direction = prop_oneof![
+        Just(Direction::Increasing),
+        Just(Direction::Decreasing),
+        Just(Direction::Either),
+        Just(Direction::NonStrict),
+    ];
+    (0..3u32, 0..6u32, direction, prop::option::of(0..20u64)).prop_map(
+        |(min_step, extra, direction, max_drift)| SafetyPolicy {
+            min_step,
+            max_step: min_step + extra,
+            direction,
+            max_drift,
+        },
+    )
+}
@@ -0,0 +1,341 @@
+//! Differential tests: each production solver against a deliberately naive
+//! reference implementation on random inputs, so optimizations can be checked
+//! for equivalence automatically.
+
+mod common;
+
+use aoc_common::{Answer, SafetyPolicy, Solution};
+use common::policy;
+use proptest::prelude::*;
+
+fn solve<S: Solution>(solution: S, input: &str) -> Answer {
+    solution.solve(input.as_bytes()).unwrap()
+}
+
+fn two_column_input(pairs: &[(i32, i32)]) -> String {
+    pairs
+        .iter()
+        .map(|(a, b)| format!("{}   {}\n", a, b))
+        .collect()
+}
+
+fn reports_input(reports: &[Vec<i32>]) -> String {
+    reports
+        .iter()
+        .map(|r| {
+            let levels: Vec<String> = r.iter().map(|l| l.to_string()).collect();
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
+
+fn naive_total_distance(pairs: &[(i32, i32)]) -> i128 {
+    let mut left: Vec<i128> = pairs.iter().map(|p| i128::from(p.0)).collect();
+    let mut right: Vec<i128> = pairs.iter().map(|p| i128::from(p.1)).collect();
+    left.sort();
+    right.sort();
+    left.iter().zip(&right).map(|(l, r)| (l - r).abs()).sum()
+}
+
+fn naive_similarity_score(pairs: &[(i32, i32)]) -> i128 {
+    pairs
+        .iter()
+        .map(|(left, _)| {
+            let count = pairs.iter().filter(|(_, right)| right == left).count();
+            i128::from(*left) * count as i128
+        })
+        .sum()
+}
+
+fn naive_is_safe(report: &[i32]) -> bool {
+    let diffs: Vec<i32> = report.windows(2).map(|w| w[1] - w[0]).collect();
+    let increasing = diffs.iter().all(|d| (1..=3).contains(d));
+    let decreasing = diffs.iter().all(|d| (-3..=-1).contains(d));
+    increasing || decreasing
+}
+
+fn naive_is_safe_with_dampener(report: &[i32]) -> bool {
+    naive_is_safe(report)
+        || (0..report.len()).any(|skip| {
+            let rest: Vec<i32> = report
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != skip)
+                .map(|(_, l)| *l)
+                .collect();
+            naive_is_safe(&rest)
+        })
+}
+
+/// Every way of removing one level, each checked from scratch.
+fn naive_is_safe_with_one_removed(report: &[i32], policy: &SafetyPolicy) -> bool {
+    (0..report.len()).any(|skip| {
+        let mut rest = report.to_vec();
+        rest.remove(skip);
+        policy.is_safe(&rest)
+    })
+}
+
+/// Tries to read `mul(a,b)` starting exactly at `at`.
+fn naive_mul_at(memory: &str, at: usize) -> Option<i128> {
+    let rest = memory[at..].strip_prefix("mul(")?;
+    let (a, rest) = rest.split_once(',')?;
+    let (b, _) = rest.split_once(')')?;
+    let is_number = |s: &str| (1..=3).contains(&s.len()) && s.bytes().all(|c| c.is_ascii_digit());
+    if is_number(a) && is_number(b) {
+        Some(a.parse::<i128>().ok()? * b.parse::<i128>().ok()?)
+    } else {
+        None
+    }
+}
+
+fn naive_sum_multiplications(memory: &str, with_conditionals: bool) -> i128 {
+    let mut enabled = true;
+    let mut sum = 0;
+    for (at, _) in memory.char_indices() {
+        if memory[at..].starts_with("do()") {
+            enabled = true;
+        } else if memory[at..].starts_with("don't()") {
+            enabled = false;
+        }
+        if enabled || !with_conditionals {
+            sum += naive_mul_at(memory, at).unwrap_or(0);
+        }
+    }
+    sum
+}
+
+fn naive_count_xmas(grid: &[Vec<char>]) -> i128 {
+    let (h, w) = (grid.len() as i64, grid[0].len() as i64);
+    let letter =
+        |x: i64, y: i64| (x >= 0 && y >= 0 && x < w && y < h).then(|| grid[y as usize][x as usize]);
+    let mut count = 0;
+    for y in 0..h {
+        for x in 0..w {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    if (dx, dy) != (0, 0)
+                        && "XMAS"
+                            .chars()
+                            .enumerate()
+                            .all(|(i, c)| letter(x + dx * i as i64, y + dy * i as i64) == Some(c))
+                    {
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+    count
+}
+
+fn naive_count_x_mas(grid: &[Vec<char>]) -> i128 {
+    let mut count = 0;
+    for y in 0..grid.len().saturating_sub(2) {
+        for x in 0..grid[0].len().saturating_sub(2) {
+            let diagonal = [grid[y][x], grid[y + 1][x + 1], grid[y + 2][x + 2]];
+            let anti_diagonal = [grid[y][x + 2], grid[y + 1][x + 1], grid[y + 2][x]];
+            let is_mas = |d: [char; 3]| d == ['M', 'A', 'S'] || d == ['S', 'A', 'M'];
+            if is_mas(diagonal) && is_mas(anti_diagonal) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn pairs() -> impl Strategy<Value = Vec<(i32, i32)>> {
+    // A small value range makes repeated IDs, and so non-zero similarity
+    // scores, likely.
+    prop::collection::vec((0..50i32, 0..50i32), 0..200)
+}
+
+/// Mostly distinct values, so the streaming depth profile grows past a single
+/// block.
+fn spread_out_pairs() -> impl Strategy<Value = Vec<(i32, i32)>> {
+    prop::collection::vec((-100_000..100_000i32, -100_000..100_000i32), 0..400)
+}
+
+fn report() -> impl Strategy<Value = Vec<i32>> {
+    prop::collection::vec(0..20i32, 1..12)
+}
+
+/// Mostly well-behaved reports, so that dampening often succeeds.
+fn monotone_report() -> impl Strategy<Value = Vec<i32>> {
+    (
+        0..20i32,
+        prop::collection::vec(-1..=3i32, 0..40),
+        any::<bool>(),
+    )
+        .prop_map(|(start, steps, falling)| {
+            let sign = if falling { -1 } else { 1 };
+            std::iter::once(start)
+                .chain(steps.iter().scan(start, |level, step| {
+                    *level += sign * step;
+                    Some(*level)
+                }))
+                .collect()
+        })
+}
+
+fn corrupted_memory() -> impl Strategy<Value = String> {
+    let fragment = prop_oneof![
+        Just("mul(".to_string()),
+        Just("mul".to_string()),
+        Just("do()".to_string()),
+        Just("don't()".to_string()),
+        Just("do(".to_string()),
+        Just("don't".to_string()),
+        Just("(".to_string()),
+        Just(")".to_string()),
+        Just(",".to_string()),
+        Just("x".to_string()),
+        // Digit runs of any length: fragments can also sit next to each
+        // other, so runs past the puzzle's 1-3 digits come up often.
+        "[0-9]{1,12}",
+        // Numerals that aren't ASCII digits, and are never operands.
+        prop::sample::select(vec!["\u{b2}", "\u{663}", "\u{bd}", "\u{216b}", "\u{ff11}"])
+            .prop_map(str::to_string),
+    ];
+    prop::collection::vec(fragment, 0..80).prop_map(|fragments| fragments.concat())
+}
+
+fn letter_grid() -> impl Strategy<Value = Vec<Vec<char>>> {
+    (1..12usize, 1..12usize).prop_flat_map(|(w, h)| {
+        prop::collection::vec(
+            prop::collection::vec(prop::sample::select(vec!['X', 'M', 'A', 'S']), w),
+            h,
+        )
+    })
+}
+
+proptest! {
+    #[test]
+    fn total_distance_matches_naive(pairs in pairs()) {
+        prop_assert_eq!(
+            solve(day_01_part1::TotalDistance, &two_column_input(&pairs)),
+            Answer::Int(naive_total_distance(&pairs))
+        );
+    }
+
+    #[test]
+    fn external_total_distance_matches_naive(
+        pairs in pairs(),
+        run_len in 1..8usize,
+        fan_in in 2..5usize,
+    ) {
+        prop_assert_eq!(
+            solve(
+                day_01_part1::ExternalTotalDistance::new(run_len).with_fan_in(fan_in),
+                &two_column_input(&pairs)
+            ),
+            Answer::Int(naive_total_distance(&pairs))
+        );
+    }
+
+    #[test]
+    fn external_merge_reads_at_most_fan_in_runs(pairs in pairs(), fan_in in 2..5usize) {
+        let input = two_column_input(&pairs);
+        let (a_runs, b_runs) = day_01_part1::spill_sorted_runs::<i128, _>(
+            input.as_bytes(),
+            1,
+            fan_in,
+            &tempfile::tempfile,
+        )
+        .unwrap();
+        prop_assert!(a_runs.num_runs() <= fan_in);
+        prop_assert!(b_runs.num_runs() <= fan_in);
+        prop_assert_eq!(
+            day_01_part1::total_distance_of_runs(&a_runs, &b_runs).unwrap(),
+            naive_total_distance(&pairs)
+        );
+    }
+
+    #[test]
+    fn streaming_totals_match_naive_after_every_pair(pairs in pairs()) {
+        let mut lists = day_01_part1::StreamingLists::new();
+        for (i, &(a, b)) in pairs.iter().enumerate() {
+            lists.push(a.into(), b.into());
+            prop_assert_eq!(lists.total_distance(), naive_total_distance(&pairs[..=i]));
+            prop_assert_eq!(lists.similarity_score(), naive_similarity_score(&pairs[..=i]));
+        }
+    }
+
+    #[test]
+    fn streaming_total_distance_matches_naive_over_many_blocks(pairs in spread_out_pairs()) {
+        let mut lists = day_01_part1::StreamingLists::new();
+        for (i, &(a, b)) in pairs.iter().enumerate() {
+            lists.push(a.into(), b.into());
+            prop_assert_eq!(lists.total_distance(), naive_total_distance(&pairs[..=i]));
+        }
+    }
+
+    #[test]
+    fn similarity_score_matches_naive(pairs in pairs()) {
+        prop_assert_eq!(
+            solve(day_01_part2::SimilarityScore, &two_column_input(&pairs)),
+            Answer::Int(naive_similarity_score(&pairs))
+        );
+    }
+
+    #[test]
+    fn report_safety_matches_naive(report in report()) {
+        prop_assert_eq!(
+            day_02_part1::determine_if_report_safe(&report).is_safe(),
+            naive_is_safe(&report)
+        );
+    }
+
+    #[test]
+    fn dampened_report_safety_matches_naive(report in report()) {
+        prop_assert_eq!(
+            day_02_part2::determine_if_removing_any_one_element_makes_report_safe(&report),
+            naive_is_safe_with_dampener(&report)
+        );
+    }
+
+    #[test]
+    fn linear_dampener_matches_every_removal(
+        report in prop_oneof![report(), monotone_report(), prop::collection::vec(0..20i32, 0..3)],
+        policy in policy(),
+    ) {
+        prop_assert_eq!(
+            day_02_part2::dampened_report_is_safe(&report, &policy),
+            naive_is_safe_with_one_removed(&report, &policy)
+        );
+    }
+
+    #[test]
+    fn safe_report_counts_match_naive(reports in prop::collection::vec(report(), 0..50)) {
+        let input = reports_input(&reports);
+        let safe = reports.iter().filter(|r| naive_is_safe(r)).count();
+        let dampened = reports.iter().filter(|r| naive_is_safe_with_dampener(r)).count();
+        prop_assert_eq!(solve(day_02_part1::SafeReports, &input), Answer::from(safe));
+        prop_assert_eq!(solve(day_02_part2::DampenedSafeReports, &input), Answer::from(dampened));
+    }
+
+    #[test]
+    fn multiplications_match_naive(memory in corrupted_memory()) {
+        prop_assert_eq!(
+            solve(day_03_part01::Multiplications, &memory),
+            Answer::Int(naive_sum_multiplications(&memory, false))
+        );
+        prop_assert_eq!(
+            solve(day_03_part02::ConditionalMultiplications, &memory),
+            Answer::Int(naive_sum_multiplications(&memory, true))
+        );
+    }
+
+    #[test]
+    fn word_search_matches_naive(grid in letter_grid()) {
+        let input: String = grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+        prop_assert_eq!(
+            solve(day_04_part01::XmasSearch, &input),
+            Answer::Int(naive_count_xmas(&grid))
+        );
+        prop_assert_eq!(
+            solve(day_04_part02::CrossMasSearch, &input),
+            Answer::Int(naive_count_x_mas(&grid))
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Every solver run against the worked example from its puzzle description.
+
+mod common;
+
+use aoc_common::{Answer, Solution};
+use common::{DAY_01, DAY_02, DAY_03_PART_1, DAY_03_PART_2, DAY_04};
+
+fn solve<S: Solution>(solution: S, input: &str) -> Answer {
+    solution.solve(input.as_bytes()).unwrap()
+}
+
+#[test]
+fn day_01_part_1() {
+    assert_eq!(solve(day_01_part1::TotalDistance, DAY_01), Answer::Int(11));
+}
+
+#[test]
+fn day_01_part_2() {
+    assert_eq!(
+        solve(day_01_part2::SimilarityScore, DAY_01),
+        Answer::Int(31)
+    );
+}
+
+#[test]
+fn day_02_part_1() {
+    assert_eq!(solve(day_02_part1::SafeReports, DAY_02), Answer::Int(2));
+}
+
+#[test]
+fn day_02_part_2() {
+    assert_eq!(
+        solve(day_02_part2::DampenedSafeReports, DAY_02),
+        Answer::Int(4)
+    );
+}
+
+#[test]
+fn day_03_part_1() {
+    assert_eq!(
+        solve(day_03_part01::Multiplications, DAY_03_PART_1),
+        Answer::Int(161)
+    );
+}
+
+#[test]
+fn day_03_part_2() {
+    assert_eq!(
+        solve(day_03_part02::ConditionalMultiplications, DAY_03_PART_2),
+        Answer::Int(48)
+    );
+}
+
+#[test]
+fn day_04_part_1() {
+    assert_eq!(solve(day_04_part01::XmasSearch, DAY_04), Answer::Int(18));
+}
+
+#[test]
+fn day_04_part_2() {
+    assert_eq!(solve(day_04_part02::CrossMasSearch, DAY_04), Answer::Int(9));
+}
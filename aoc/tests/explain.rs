@@ -0,0 +1,68 @@
+//! `--explain` for day 1: the rows behind each answer, from the library and
+//! through the `aoc` binary.
+
+mod common;
+
+use common::{run_aoc, DAY_01, DAY_01_FILE};
+use day_01_part1::{explain_total_distance, PairDistance};
+use day_01_part2::{explain_similarity_score, LeftScore};
+
+#[test]
+fn total_distance_rows_are_the_sorted_pairing() {
+    let (a_list, b_list) = day_01_part1::parse_location_lists::<i64, _>(DAY_01.as_bytes()).unwrap();
+    let rows = explain_total_distance(&a_list, &b_list).unwrap();
+    let distances: Vec<i64> = rows.iter().map(|row| row.distance).collect();
+    assert_eq!(distances, vec![2, 1, 0, 1, 2, 5]);
+    assert_eq!(
+        rows[5],
+        PairDistance {
+            left: 4,
+            right: 9,
+            distance: 5
+        }
+    );
+}
+
+#[test]
+fn similarity_rows_follow_the_left_list() {
+    let (left_list, right_counts) =
+        day_01_part2::parse_left_list_and_right_counts::<i64, _>(DAY_01.as_bytes()).unwrap();
+    let rows = explain_similarity_score(&left_list, &right_counts).unwrap();
+    assert_eq!(rows.iter().map(|row| row.score).sum::<i64>(), 31);
+    assert_eq!(
+        rows[0],
+        LeftScore {
+            left: 3,
+            occurrences: 3,
+            score: 9
+        }
+    );
+}
+
+#[test]
+fn csv_keeps_the_answer_off_stdout() {
+    let (stdout, stderr) =
+        run_aoc(&["run", "1", "1", "--input", DAY_01_FILE, "--explain=csv"]).unwrap();
+    assert_eq!(
+        stdout,
+        "left,right,distance\n1,3,2\n2,3,1\n3,3,0\n3,4,1\n3,5,2\n4,9,5\n"
+    );
+    assert_eq!(stderr, "day 1 part 1: 11\n");
+}
+
+#[test]
+fn table_is_the_default_format() {
+    let (stdout, _) = run_aoc(&["run", "1", "2", "--input", DAY_01_FILE, "--explain"]).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "left  occurrences  score");
+    assert_eq!(lines[2], "   3            3      9");
+    assert_eq!(lines.last(), Some(&"day 1 part 2: 31"));
+}
+
+#[test]
+fn json_has_one_object_per_row() {
+    let (stdout, _) =
+        run_aoc(&["run", "1", "1", "--input", DAY_01_FILE, "--explain=json"]).unwrap();
+    assert!(stdout.starts_with("[\n  {\"left\": 1, \"right\": 3, \"distance\": 2},\n"));
+    assert_eq!(stdout.matches("\"distance\"").count(), 6);
+}
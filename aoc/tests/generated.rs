@@ -0,0 +1,92 @@
+//! Every solver against inputs from `aoc-gen`, whose answers are known by
+//! construction.
+
+use aoc_common::{Answer, Solution};
+
+fn check<P1: Solution, P2: Solution>(day: u8, size: usize, part1: P1, part2: P2) {
+    for seed in 0..5 {
+        let generated = aoc_gen::generate(day, seed, size).unwrap();
+        let input = generated.input.as_bytes();
+        assert_eq!(
+            part1.solve(input).unwrap(),
+            generated.part1,
+            "day {} part 1, seed {}",
+            day,
+            seed
+        );
+        assert_eq!(
+            part2.solve(input).unwrap(),
+            generated.part2,
+            "day {} part 2, seed {}",
+            day,
+            seed
+        );
+    }
+}
+
+#[test]
+fn day_01() {
+    check(
+        1,
+        1000,
+        day_01_part1::TotalDistance,
+        day_01_part2::SimilarityScore,
+    );
+}
+
+#[test]
+fn day_02() {
+    check(
+        2,
+        1000,
+        day_02_part1::SafeReports,
+        day_02_part2::DampenedSafeReports,
+    );
+}
+
+#[test]
+fn day_03() {
+    // Big enough for both sums to pass `i32::MAX`.
+    check(
+        3,
+        400,
+        day_03_part01::Multiplications,
+        day_03_part02::ConditionalMultiplications,
+    );
+}
+
+#[test]
+fn day_03_operands_are_one_to_three_ascii_digits() {
+    let memory = "mul(99999999999,2)mul(1234,5)mul(1\u{b2},2)mul(\u{663},4)mul(12,3)do()";
+    for answer in [
+        day_03_part01::Multiplications.solve(memory.as_bytes()),
+        day_03_part02::ConditionalMultiplications.solve(memory.as_bytes()),
+    ] {
+        assert_eq!(answer.unwrap(), Answer::Int(36));
+    }
+}
+
+#[test]
+fn day_04() {
+    check(
+        4,
+        140,
+        day_04_part01::XmasSearch,
+        day_04_part02::CrossMasSearch,
+    );
+}
+
+#[test]
+fn same_seed_same_input() {
+    for day in 1..=4 {
+        let a = aoc_gen::generate(day, 7, 20).unwrap();
+        let b = aoc_gen::generate(day, 7, 20).unwrap();
+        assert_eq!(a.input, b.input);
+        assert_eq!((a.part1, a.part2), (b.part1, b.part2));
+    }
+    assert_ne!(
+        aoc_gen::generate(1, 1, 20).unwrap().input,
+        aoc_gen::generate(1, 2, 20).unwrap().input
+    );
+    assert_eq!(aoc_gen::generate(5, 1, 20).map(|g| g.part1), None::<Answer>);
+}
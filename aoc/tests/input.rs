@@ -0,0 +1,121 @@
+//! Memory-mapped and buffered inputs against the reader based path.
+
+mod common;
+
+use std::io::Write;
+
+use aoc_common::{grid, Input, Solver};
+use common::{DAY_01, DAY_02, DAY_03_PART_1, DAY_03_PART_2, DAY_04};
+use tempfile::NamedTempFile;
+
+fn solvers_with_inputs() -> Vec<(Box<dyn Solver>, &'static str)> {
+    vec![
+        (Box::new(day_01_part1::TotalDistance), DAY_01),
+        (
+            Box::new(day_01_part1::ParallelTotalDistance::new(2)),
+            DAY_01,
+        ),
+        (Box::new(day_01_part2::SimilarityScore), DAY_01),
+        (
+            Box::new(day_01_part2::ParallelSimilarityScore::new(2)),
+            DAY_01,
+        ),
+        (Box::new(day_02_part1::SafeReports), DAY_02),
+        (Box::new(day_02_part2::DampenedSafeReports), DAY_02),
+        (Box::new(day_03_part01::Multiplications), DAY_03_PART_1),
+        (
+            Box::new(day_03_part02::ConditionalMultiplications),
+            DAY_03_PART_2,
+        ),
+        (Box::new(day_04_part01::XmasSearch), DAY_04),
+        (Box::new(day_04_part02::CrossMasSearch), DAY_04),
+    ]
+}
+
+fn file_with(contents: &[u8]) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(contents).unwrap();
+    file.flush().unwrap();
+    file
+}
+
+/// The answer (or error) from reading `input` and from handing it over in
+/// memory.
+fn solve_both(solver: &dyn Solver, input: &[u8]) -> [Result<String, String>; 2] {
+    [solver.solve(&mut &input[..]), solver.solve_bytes(input)]
+        .map(|answer| answer.map(|a| a.to_string()).map_err(|e| e.to_string()))
+}
+
+#[test]
+fn mapped_files_solve_like_readers() {
+    for (solver, fixture) in solvers_with_inputs() {
+        let file = file_with(fixture.as_bytes());
+        let input = Input::open(file.path()).unwrap();
+        assert!(input.is_mapped());
+        assert_eq!(input.bytes(), fixture.as_bytes());
+
+        let [read, in_memory] = solve_both(solver.as_ref(), &input);
+        assert!(read.is_ok(), "{}: {:?}", solver.name(), read);
+        assert_eq!(read, in_memory, "{}", solver.name());
+    }
+}
+
+#[test]
+fn bad_inputs_fail_the_same_way_in_memory() {
+    let inputs: [&[u8]; 7] = [
+        b"",
+        b"\n",
+        b"1 2\n3\n",
+        b"1 2\r\n3 x\r\n",
+        b"mul(2,3)\n\xffmul(4,5)\n",
+        b"XMAS\nXMA\n",
+        b"XMAS\nSAMX\n\xc3\n",
+    ];
+    for (solver, _) in solvers_with_inputs() {
+        for input in inputs {
+            let [read, in_memory] = solve_both(solver.as_ref(), input);
+            assert_eq!(read, in_memory, "{} on {:?}", solver.name(), input);
+        }
+    }
+}
+
+#[test]
+fn empty_files_and_readers_are_buffered() {
+    let file = file_with(b"");
+    let input = Input::open(file.path()).unwrap();
+    assert!(!input.is_mapped());
+    assert!(input.bytes().is_empty());
+
+    let input = Input::read(&b"3   4\n4   3\n"[..]).unwrap();
+    assert!(!input.is_mapped());
+    assert_eq!(input.text().unwrap(), "3   4\n4   3\n");
+}
+
+#[test]
+fn missing_files_name_the_file() {
+    let e = Input::open("no/such/input.txt").err().unwrap();
+    assert!(
+        e.to_string().starts_with("no/such/input.txt: I/O error"),
+        "{}",
+        e
+    );
+}
+
+#[test]
+fn grids_from_files_match_grids_from_readers() {
+    let file = file_with(DAY_04.as_bytes());
+    let path = file.path().to_str().unwrap();
+    assert_eq!(
+        grid::build_puzzle_map_from_file(path).unwrap(),
+        grid::build_puzzle_map(DAY_04.as_bytes()).unwrap()
+    );
+}
+
+#[test]
+fn only_the_external_sort_streams_its_input() {
+    for (solver, _) in solvers_with_inputs() {
+        assert!(!solver.streams_input(), "{}", solver.name());
+    }
+    let external: Box<dyn Solver> = Box::new(day_01_part1::ExternalTotalDistance::new(2));
+    assert!(external.streams_input());
+}
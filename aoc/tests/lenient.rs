@@ -0,0 +1,100 @@
+//! `--lenient` day 2: malformed and blank lines set aside instead of failing
+//! the whole input.
+
+mod common;
+
+use std::io::Write;
+
+use aoc_common::{
+    reports::{parse_report_bytes, parse_report_bytes_leniently},
+    ErrorKind, NumberScanner,
+};
+use common::{run_aoc, DAY_02};
+use proptest::prelude::*;
+use tempfile::NamedTempFile;
+
+const MESSY: &str = "7 6 4 2 1\n1 2 x 8\n\n5\n9 7 99999999999 2\n   \n1 3 2 4 5";
+
+#[test]
+fn malformed_and_blank_lines_are_set_aside() {
+    let parsed = parse_report_bytes_leniently(MESSY.as_bytes());
+    assert_eq!(
+        parsed.reports,
+        vec![vec![7, 6, 4, 2, 1], vec![5], vec![1, 3, 2, 4, 5]]
+    );
+    let lines: Vec<(usize, usize)> = parsed
+        .malformed
+        .iter()
+        .map(|e| {
+            assert!(matches!(e.kind(), ErrorKind::NotParseableAsNumber(_)));
+            let location = e.location().unwrap();
+            (location.line, location.column)
+        })
+        .collect();
+    assert_eq!(lines, vec![(2, 5), (5, 5)]);
+    assert_eq!(parsed.blank_lines, vec![3, 6]);
+}
+
+#[test]
+fn single_levels_are_safe_under_both_parts() {
+    let reports = vec![vec![5]];
+    assert_eq!(day_02_part1::count_safe_reports(&reports), 1);
+    assert_eq!(day_02_part2::count_safe_reports(&reports), 1);
+}
+
+#[test]
+fn skipping_a_line_resumes_at_the_next() {
+    let mut scanner = NumberScanner::<i32>::new(b"1 x 3\n4 5");
+    let mut levels = Vec::new();
+    assert!(scanner.read_line(&mut levels).unwrap().is_err());
+    scanner.skip_line();
+    assert_eq!(scanner.read_line(&mut levels).unwrap().unwrap(), 2);
+    assert_eq!(levels, vec![4, 5]);
+    assert!(scanner.read_line(&mut levels).is_none());
+}
+
+proptest! {
+    #[test]
+    fn clean_inputs_parse_the_same_either_way(
+        reports in prop::collection::vec(prop::collection::vec(any::<i32>(), 1..8), 0..20),
+    ) {
+        let input: String = reports
+            .iter()
+            .map(|report| {
+                let levels: Vec<String> = report.iter().map(i32::to_string).collect();
+                levels.join(" ") + "\n"
+            })
+            .collect();
+        let parsed = parse_report_bytes_leniently(input.as_bytes());
+        prop_assert!(parsed.malformed.is_empty());
+        prop_assert_eq!(parsed.reports, parse_report_bytes(input.as_bytes()).unwrap());
+    }
+}
+
+#[test]
+fn lenient_runs_print_a_summary() {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(MESSY.as_bytes()).unwrap();
+    let path = file.path().to_str().unwrap();
+
+    let (stdout, _) = run_aoc(&["run", "2", "1", "--input", path, "--lenient"]).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[0], "day 2 part 1: 2");
+    assert_eq!(
+        lines[1],
+        "3 reports read, 2 malformed lines skipped, 2 blank lines skipped"
+    );
+    assert!(lines[2].starts_with(&format!("  {}:2:5 (byte 14): not parseable", path)));
+    assert!(lines[3].contains("number too large"));
+    assert_eq!(lines[4], "  blank lines: 3,6");
+}
+
+#[test]
+fn strict_runs_still_stop_at_the_first_bad_line() {
+    assert!(parse_report_bytes(MESSY.as_bytes()).is_err());
+    let parsed = parse_report_bytes_leniently(DAY_02.as_bytes());
+    assert_eq!(
+        parsed.reports,
+        parse_report_bytes(DAY_02.as_bytes()).unwrap()
+    );
+}
@@ -0,0 +1,99 @@
+//! The parallel day 1 solvers against the sequential ones, including which
+//! error they report, for every thread count.
+
+use aoc_common::SolveError;
+use proptest::prelude::*;
+
+fn outcome<T: ToString>(result: Result<T, SolveError>) -> String {
+    match result {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+/// IDs near the edges of `i32` so that sums overflow at that width, mixed
+/// with small ones so that they often don't.
+fn id() -> impl Strategy<Value = i64> {
+    prop_oneof![
+        -5..5i64,
+        Just(i64::from(i32::MAX)),
+        Just(i64::from(i32::MIN)),
+        Just(i64::from(i32::MAX / 2)),
+    ]
+}
+
+fn input() -> impl Strategy<Value = String> {
+    let line = prop_oneof![
+        8 => (id(), id()).prop_map(|(a, b)| format!("{}   {}", a, b)),
+        1 => Just("1 2 3".to_string()),
+        1 => Just("7 x".to_string()),
+    ];
+    prop::collection::vec(line, 0..40)
+        .prop_map(|lines| lines.iter().map(|line| format!("{}\n", line)).collect())
+}
+
+proptest! {
+    #[test]
+    fn parallel_total_distance_matches_sequential(input in input(), threads in 1..6usize) {
+        prop_assert_eq!(
+            outcome(day_01_part1::find_total_distance_parallel::<i32>(input.as_bytes(), threads)),
+            outcome(day_01_part1::find_total_distance::<i32, _>(input.as_bytes()))
+        );
+    }
+
+    #[test]
+    fn parallel_similarity_score_matches_sequential(input in input(), threads in 1..6usize) {
+        prop_assert_eq!(
+            outcome(day_01_part2::find_similarity_score_parallel::<i32>(input.as_bytes(), threads)),
+            outcome(day_01_part2::find_similarity_score::<i32, _>(input.as_bytes()))
+        );
+    }
+
+    #[test]
+    fn parallel_sort_sorts(mut values in prop::collection::vec(any::<i16>(), 0..200), threads in 1..9usize) {
+        let mut expected = values.clone();
+        expected.sort();
+        aoc_common::parallel::par_sort(&mut values, threads).unwrap();
+        prop_assert_eq!(values, expected);
+    }
+}
+
+#[test]
+fn errors_point_at_the_line_in_the_whole_input() {
+    let mut input = "1   2\n".repeat(50);
+    input.push_str("3   oops\n");
+    input.push_str(&"1   2\n".repeat(50));
+
+    let err = day_01_part1::find_total_distance_parallel::<i64>(input.as_bytes(), 4).unwrap_err();
+    assert_eq!(err.location().unwrap().line, 51);
+}
+
+#[test]
+fn more_threads_than_work_or_cores_is_fine() {
+    let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    assert_eq!(
+        day_01_part1::find_total_distance_parallel::<i64>(input.as_bytes(), 1_000_000).unwrap(),
+        11
+    );
+    assert_eq!(
+        aoc_common::parallel::line_chunks(input.as_bytes(), 1_000_000).len(),
+        6
+    );
+
+    let solver = day_01_part2::ParallelSimilarityScore::new(1_000_000);
+    assert_eq!(
+        aoc_common::Solution::solve_bytes(&solver, input.as_bytes())
+            .unwrap()
+            .to_string(),
+        "31"
+    );
+}
+
+#[test]
+fn thread_counts_are_capped_at_available_cores() {
+    use aoc_common::parallel::{available_threads, clamp_threads};
+
+    assert_eq!(clamp_threads(0), available_threads());
+    assert_eq!(clamp_threads(1_000_000), available_threads());
+    assert_eq!(clamp_threads(1), 1);
+}
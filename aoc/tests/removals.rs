@@ -0,0 +1,79 @@
+//! Day 2 reports made safe by removing any number of levels, against trying
+//! every subset of levels.
+
+mod common;
+
+use aoc_common::SafetyPolicy;
+use common::{policy, run_aoc, DAY_02_FILE};
+use day_02_part2::{dampened_report_is_safe, fewest_removals, removal_histogram};
+use proptest::prelude::*;
+
+/// The fewest removals found by checking every subset of kept levels.
+fn naive_fewest_removals(report: &[i32], policy: &SafetyPolicy) -> usize {
+    (0u32..1 << report.len())
+        .filter_map(|kept| {
+            let levels: Vec<i32> = (0..report.len())
+                .filter(|i| kept & (1 << i) != 0)
+                .map(|i| report[i])
+                .collect();
+            policy.is_safe(&levels).then(|| report.len() - levels.len())
+        })
+        .min()
+        .unwrap()
+}
+
+proptest! {
+    #[test]
+    fn fewest_removals_match_every_subset(
+        report in prop::collection::vec(0..15i32, 0..11),
+        policy in policy(),
+    ) {
+        let dropped = fewest_removals(&report, &policy);
+        prop_assert_eq!(dropped.len(), naive_fewest_removals(&report, &policy));
+
+        prop_assert!(dropped.windows(2).all(|w| w[0] < w[1]));
+        let kept: Vec<i32> = (0..report.len())
+            .filter(|i| !dropped.contains(i))
+            .map(|i| report[i])
+            .collect();
+        prop_assert!(policy.is_safe(&kept));
+    }
+
+    #[test]
+    fn one_removal_is_the_problem_dampener(
+        reports in prop::collection::vec(prop::collection::vec(0..15i32, 1..9), 0..20),
+        policy in policy(),
+    ) {
+        let dampened = reports
+            .iter()
+            .filter(|report| dampened_report_is_safe(report, &policy))
+            .count();
+        prop_assert_eq!(removal_histogram(&reports, &policy, 1).safe(), dampened);
+    }
+}
+
+#[test]
+fn max_removals_prints_indices_and_histogram() {
+    let (stdout, _) =
+        run_aoc(&["run", "2", "2", "--input", DAY_02_FILE, "--max-removals=2"]).unwrap();
+    assert_eq!(
+        stdout,
+        "line 2: remove 2 (indices 0,1)
+line 3: remove 2 (indices 3,4)
+line 4: remove 1 (indices 2)
+line 5: remove 1 (indices 3)
+removals  reports
+0         2
+1         2
+2         2
+>2        0
+day 2 part 2 (at most 2 removals): 6
+"
+    );
+}
+
+#[test]
+fn max_removals_is_only_for_day_2_part_2() {
+    let e = run_aoc(&["run", "2", "1", "--input", DAY_02_FILE, "--max-removals=1"]).unwrap_err();
+    assert!(e.contains("--max-removals is not supported for day 2 part 1"));
+}
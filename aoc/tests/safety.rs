@@ -0,0 +1,141 @@
+//! Day 2 safety policies, from the library and through the `aoc` binary.
+
+mod common;
+
+use std::io::Write;
+
+use aoc_common::{safety::Direction, Answer, SafetyPolicy, Solution};
+use common::{run_aoc, DAY_02, DAY_02_FILE};
+use day_02_part1::PolicySafeReports;
+use day_02_part2::PolicyDampenedSafeReports;
+use tempfile::NamedTempFile;
+
+fn safe(policy: SafetyPolicy) -> Answer {
+    PolicySafeReports::new(policy)
+        .solve(DAY_02.as_bytes())
+        .unwrap()
+}
+
+fn dampened(policy: SafetyPolicy) -> Answer {
+    PolicyDampenedSafeReports::new(policy)
+        .solve(DAY_02.as_bytes())
+        .unwrap()
+}
+
+#[test]
+fn default_policy_is_the_puzzle_rule() {
+    assert_eq!(safe(SafetyPolicy::default()), Answer::Int(2));
+    assert_eq!(dampened(SafetyPolicy::default()), Answer::Int(4));
+}
+
+#[test]
+fn directions_limit_which_way_reports_move() {
+    let policy = |direction| SafetyPolicy {
+        direction,
+        ..SafetyPolicy::default()
+    };
+    assert_eq!(safe(policy(Direction::Increasing)), Answer::Int(1));
+    assert_eq!(safe(policy(Direction::Decreasing)), Answer::Int(1));
+    assert_eq!(dampened(policy(Direction::Increasing)), Answer::Int(2));
+
+    // Flat steps only pass when they are also allowed to be 0 long.
+    assert_eq!(safe(policy(Direction::NonStrict)), Answer::Int(2));
+    let flat = SafetyPolicy {
+        min_step: 0,
+        ..policy(Direction::NonStrict)
+    };
+    assert_eq!(safe(flat), Answer::Int(3));
+    assert!(!SafetyPolicy {
+        min_step: 0,
+        ..SafetyPolicy::default()
+    }
+    .is_safe(&[8, 6, 4, 4, 1]));
+}
+
+#[test]
+fn step_and_drift_limits() {
+    let wide = SafetyPolicy {
+        max_step: 5,
+        ..SafetyPolicy::default()
+    };
+    assert_eq!(safe(wide), Answer::Int(4));
+
+    let short = SafetyPolicy {
+        max_drift: Some(6),
+        ..SafetyPolicy::default()
+    };
+    assert_eq!(safe(short), Answer::Int(1));
+    assert!(short.is_safe(&[]));
+    assert!(short.is_safe(&[i32::MAX]));
+}
+
+#[test]
+fn extreme_levels_do_not_overflow() {
+    let policy = SafetyPolicy {
+        max_step: u32::MAX,
+        ..SafetyPolicy::default()
+    };
+    assert!(policy.is_safe(&[i32::MIN, i32::MAX]));
+    assert!(!SafetyPolicy::default().is_safe(&[i32::MAX, i32::MIN]));
+}
+
+#[test]
+fn policy_files_and_flags_from_the_command_line() {
+    let mut file = NamedTempFile::new().unwrap();
+    writeln!(file, "direction = \"increasing\"\nmax_step = 4").unwrap();
+    let policy = file.path().to_str().unwrap();
+
+    let run = |extra: &[&str]| {
+        let mut args = vec!["run", "2", "1", "--input", DAY_02_FILE];
+        args.extend(extra);
+        run_aoc(&args).map(|(stdout, _)| stdout)
+    };
+    assert_eq!(run(&[]).unwrap(), "day 2 part 1: 2\n");
+    let with_file = format!("--policy={}", policy);
+    assert_eq!(run(&[&with_file]).unwrap(), "day 2 part 1: 1\n");
+    // Flags override the file.
+    assert_eq!(
+        run(&[&with_file, "--max-step=5"]).unwrap(),
+        "day 2 part 1: 2\n"
+    );
+    assert_eq!(
+        run(&["--direction=decreasing"]).unwrap(),
+        "day 2 part 1: 1\n"
+    );
+}
+
+#[test]
+fn bad_policies_are_rejected() {
+    let run = |extra: &str| run_aoc(&["run", "2", "1", "--input", DAY_02_FILE, extra]).unwrap_err();
+    assert!(run("--direction=sideways").contains("unknown direction 'sideways'"));
+    assert!(run("--min-step=4").contains("minimum step 4 is larger than maximum step 3"));
+
+    let mut file = NamedTempFile::new().unwrap();
+    writeln!(file, "max_steps = 4").unwrap();
+    let with_file = format!("--policy={}", file.path().display());
+    assert!(run(&with_file).contains("could not parse"));
+
+    let answers = NamedTempFile::new().unwrap();
+    let e = run_aoc(&[
+        "record",
+        "2",
+        "1",
+        "--input",
+        DAY_02_FILE,
+        "--answers",
+        answers.path().to_str().unwrap(),
+        "--max-step=5",
+    ])
+    .unwrap_err();
+    assert!(e.contains("can't be recorded"), "{}", e);
+}
+
+#[test]
+fn dampener_handles_long_reports() {
+    let mut report: Vec<i32> = (0..200_000).collect();
+    assert!(day_02_part2::determine_if_removing_any_one_element_makes_report_safe(&report));
+    report[100_000] = -7;
+    assert!(day_02_part2::determine_if_removing_any_one_element_makes_report_safe(&report));
+    report[150_000] = -7;
+    assert!(!day_02_part2::determine_if_removing_any_one_element_makes_report_safe(&report));
+}
@@ -0,0 +1,104 @@
+//! The byte-level `NumberScanner` against the `str` based line parser it
+//! replaces.
+
+use aoc_common::{numbered_lines, parse_into_numbers, NumberScanner, SolveError};
+use proptest::prelude::*;
+
+type Outcome<T> = Result<Vec<Vec<T>>, (String, usize, usize, String)>;
+
+fn describe(e: SolveError) -> (String, usize, usize, String) {
+    let location = e.location().cloned().unwrap();
+    (
+        e.kind().to_string(),
+        location.line,
+        location.column,
+        location.snippet,
+    )
+}
+
+fn with_lines<T: aoc_common::CheckedInt>(input: &str) -> Outcome<T> {
+    numbered_lines(input.as_bytes())
+        .map(parse_into_numbers)
+        .collect::<Result<_, _>>()
+        .map_err(describe)
+}
+
+fn with_scanner<T: aoc_common::CheckedInt>(input: &str) -> Outcome<T> {
+    let mut scanner = NumberScanner::new(input.as_bytes());
+    let mut levels = Vec::new();
+    let mut lines = Vec::new();
+    while let Some(line) = scanner.read_line(&mut levels) {
+        line.map_err(describe)?;
+        lines.push(levels.clone());
+    }
+    Ok(lines)
+}
+
+fn text() -> impl Strategy<Value = String> {
+    let piece = prop_oneof![
+        4 => any::<i64>().prop_map(|n| n.to_string()),
+        2 => (0..1000u32).prop_map(|n| n.to_string()),
+        1 => prop::sample::select(vec![
+            "-", "+", "+7", "-0", "007", "1-2", "x", "12a", "--3",
+            "99999999999999999999", "-9223372036854775808", "-9223372036854775809",
+        ])
+        .prop_map(str::to_string),
+        4 => prop::sample::select(vec![" ", "  ", "\t", "\n", "\r\n", " \n", "\x0b", "\x0c"])
+            .prop_map(str::to_string),
+    ];
+    prop::collection::vec(piece, 0..30).prop_map(|pieces| pieces.concat())
+}
+
+proptest! {
+    #[test]
+    fn scanner_matches_line_parser_for_i32(input in text()) {
+        prop_assert_eq!(with_scanner::<i32>(&input), with_lines::<i32>(&input));
+    }
+
+    #[test]
+    fn scanner_matches_line_parser_for_i64(input in text()) {
+        prop_assert_eq!(with_scanner::<i64>(&input), with_lines::<i64>(&input));
+    }
+
+    #[test]
+    fn scanner_matches_line_parser_for_u64(input in text()) {
+        prop_assert_eq!(with_scanner::<u64>(&input), with_lines::<u64>(&input));
+    }
+}
+
+#[test]
+fn reports_the_byte_offset_of_a_bad_token() {
+    let input = "1 2\n3  4x 5\n";
+    let err = day_02_part1::parse_report_bytes(input.as_bytes()).unwrap_err();
+    let location = err.location().unwrap();
+    assert_eq!((location.line, location.column), (2, 4));
+    assert_eq!(location.offset, Some(7));
+    assert_eq!(location.snippet, "4x");
+    assert!(err.to_string().starts_with("<input>:2:4 (byte 7): "));
+}
+
+#[test]
+fn reports_the_byte_offset_of_a_short_line() {
+    let err =
+        day_01_part1::parse_location_list_bytes::<i64>("1   2\n  3\n".as_bytes()).unwrap_err();
+    let location = err.location().unwrap();
+    assert_eq!((location.line, location.offset), (2, Some(8)));
+    assert_eq!(location.snippet, "3");
+}
+
+#[test]
+fn parallel_parse_offsets_count_from_the_start_of_the_input() {
+    let mut input = "1   2\n".repeat(40);
+    input.push_str("3   oops\n");
+    let err = day_01_part1::find_total_distance_parallel::<i64>(input.as_bytes(), 4).unwrap_err();
+    assert_eq!(err.location().unwrap().offset, Some(40 * 6 + 4));
+}
+
+#[test]
+fn extreme_values() {
+    assert_eq!(
+        with_scanner::<i64>("-9223372036854775808 9223372036854775807"),
+        Ok(vec![vec![i64::MIN, i64::MAX]])
+    );
+    assert!(with_scanner::<u64>("-0").is_err());
+}
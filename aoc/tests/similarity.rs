@@ -0,0 +1,81 @@
+//! The `OccurrenceCounter` multiset and the day 1 similarity variants built on
+//! it.
+
+mod common;
+
+use aoc_common::OccurrenceCounter;
+use common::DAY_01;
+use day_01_part2::{
+    find_cosine_similarity, find_similarity_score, find_symmetric_similarity_score,
+    find_weighted_jaccard,
+};
+
+fn counter(values: &[i32]) -> OccurrenceCounter<i32> {
+    values.iter().copied().collect()
+}
+
+fn entries(counter: &OccurrenceCounter<i32>) -> Vec<(i32, usize)> {
+    counter.iter().map(|(n, count)| (*n, count)).collect()
+}
+
+#[test]
+fn iterates_in_sorted_order() {
+    let counts = counter(&[5, 3, 9, 3, 5, 3]);
+    assert_eq!(entries(&counts), vec![(3, 3), (5, 2), (9, 1)]);
+    assert_eq!(counts.len(), 6);
+    assert_eq!(counts.distinct(), 3);
+    assert_eq!(counts.num_occurances(&4), 0);
+}
+
+#[test]
+fn merge_intersection_and_difference() {
+    let a = counter(&[1, 1, 2, 3]);
+    let b = counter(&[1, 2, 2, 4]);
+
+    let mut merged = a.clone();
+    merged.merge(&b);
+    assert_eq!(entries(&merged), vec![(1, 3), (2, 3), (3, 1), (4, 1)]);
+    assert_eq!(entries(&a.intersection(&b)), vec![(1, 1), (2, 1)]);
+    assert_eq!(entries(&a.difference(&b)), vec![(1, 1), (3, 1)]);
+    assert_eq!(entries(&b.difference(&a)), vec![(2, 1), (4, 1)]);
+}
+
+#[test]
+fn top_k_prefers_higher_counts_then_smaller_values() {
+    let counts = counter(&[7, 7, 2, 2, 9, 9, 9, 1]);
+    assert_eq!(counts.top_k(3), vec![(&9, 3), (&2, 2), (&7, 2)]);
+    assert_eq!(counts.top_k(10).len(), 4);
+}
+
+#[test]
+fn extend_with_values_and_counts() {
+    let mut counts = counter(&[1]);
+    counts.extend([1, 2]);
+    counts.extend([(3, 4), (1, 0)]);
+    assert_eq!(entries(&counts), vec![(1, 2), (2, 1), (3, 4)]);
+}
+
+#[test]
+fn symmetric_score_counts_both_directions() {
+    assert_eq!(
+        find_similarity_score::<i64, _>(DAY_01.as_bytes()).unwrap(),
+        31
+    );
+    assert_eq!(
+        find_symmetric_similarity_score::<i64, _>(DAY_01.as_bytes()).unwrap(),
+        62
+    );
+}
+
+#[test]
+fn histogram_similarities() {
+    assert_eq!(find_weighted_jaccard(DAY_01.as_bytes()).unwrap(), 0.5);
+    let cosine = find_cosine_similarity(DAY_01.as_bytes()).unwrap();
+    assert!((cosine - 10.0 / 12.0).abs() < 1e-12);
+}
+
+#[test]
+fn histogram_similarities_of_empty_input() {
+    assert_eq!(find_weighted_jaccard("".as_bytes()).unwrap(), 1.0);
+    assert_eq!(find_cosine_similarity("".as_bytes()).unwrap(), 0.0);
+}
@@ -0,0 +1,84 @@
+//! Day 2 per-report statistics, from the library and through `--stats`.
+
+mod common;
+
+use aoc_common::SafetyPolicy;
+use common::{run_aoc, DAY_02_FILE};
+use day_02_part2::{report_stats, ReportStats, Shape};
+
+#[test]
+fn stats_of_a_dampened_report() {
+    assert_eq!(
+        report_stats(&[8, 6, 4, 4, 1], &SafetyPolicy::default()),
+        ReportStats {
+            length: 5,
+            shape: Shape::NonIncreasing,
+            min_step: Some(0),
+            max_step: Some(3),
+            mean_step: Some(1.75),
+            violations: 1,
+            safe: false,
+            dampened_safe: true,
+            dampened_index: Some(2),
+        }
+    );
+}
+
+#[test]
+fn short_reports_have_no_steps() {
+    for report in [&[][..], &[4]] {
+        let stats = report_stats(report, &SafetyPolicy::default());
+        assert_eq!(stats.shape, Shape::None);
+        assert_eq!((stats.min_step, stats.mean_step), (None, None));
+        assert!(stats.safe);
+    }
+    assert_eq!(
+        report_stats(&[3, 3, 3], &SafetyPolicy::default()).shape,
+        Shape::Flat
+    );
+}
+
+#[test]
+fn csv_has_a_header_and_a_row_per_line() {
+    let (stdout, stderr) =
+        run_aoc(&["run", "2", "2", "--input", DAY_02_FILE, "--stats=csv"]).unwrap();
+    let rows: Vec<&str> = stdout.lines().collect();
+    assert_eq!(rows.len(), 7);
+    assert_eq!(
+        rows[0],
+        "line,length,shape,min_step,max_step,mean_step,violations,safe_part1,safe_part2,dampened_index,levels"
+    );
+    assert_eq!(rows[4], "4,5,mixed,1,2,1.5,1,false,true,1,1 3 2 4 5");
+    assert_eq!(stderr, "day 2 part 2: 4\n");
+}
+
+#[test]
+fn json_lines_are_one_object_per_line() {
+    let (stdout, stderr) =
+        run_aoc(&["run", "2", "1", "--input", DAY_02_FILE, "--stats=jsonl"]).unwrap();
+    let rows: Vec<&str> = stdout.lines().collect();
+    assert_eq!(rows.len(), 6);
+    assert!(rows
+        .iter()
+        .all(|row| row.starts_with('{') && row.ends_with('}')));
+    assert_eq!(
+        rows[1],
+        "{\"line\": 2, \"length\": 5, \"shape\": \"increasing\", \"min_step\": 1, \"max_step\": 5, \
+         \"mean_step\": 2, \"violations\": 1, \"safe_part1\": false, \"safe_part2\": false, \
+         \"dampened_index\": null, \"levels\": [1,2,7,8,9]}"
+    );
+    assert_eq!(stderr, "day 2 part 1: 2\n");
+}
+
+#[test]
+fn only_day_2_parts_have_stats() {
+    for (day, part) in [("1", "1"), ("2", "3")] {
+        assert_eq!(
+            run_aoc(&["run", day, part, "--input", DAY_02_FILE, "--stats=csv"]),
+            Err(format!(
+                "--stats is not supported for day {} part {}\n",
+                day, part
+            ))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Day 2 verdicts: where and why a report is unsafe, and which removal fixes
+//! it, from the library and through `--report`.
+
+mod common;
+
+use aoc_common::{
+    safety::{Verdict, Violation},
+    SafetyPolicy,
+};
+use common::{policy, run_aoc, run_aoc_with_stdin, DAY_02, DAY_02_FILE};
+use day_02_part1::determine_if_report_safe;
+use proptest::prelude::*;
+
+fn unsafe_at(at: usize, violation: Violation, fixed_by: Option<usize>) -> Verdict {
+    Verdict::Unsafe {
+        at,
+        violation,
+        fixed_by,
+    }
+}
+
+#[test]
+fn puzzle_examples() {
+    assert_eq!(determine_if_report_safe(&[7, 6, 4, 2, 1]), Verdict::Safe);
+    assert_eq!(
+        determine_if_report_safe(&[1, 2, 7, 8, 9]),
+        unsafe_at(2, Violation::StepSize, None)
+    );
+    assert_eq!(
+        determine_if_report_safe(&[1, 3, 2, 4, 5]),
+        unsafe_at(2, Violation::Direction, Some(1))
+    );
+    assert_eq!(
+        determine_if_report_safe(&[8, 6, 4, 4, 1]),
+        unsafe_at(3, Violation::StepSize, Some(2))
+    );
+    assert_eq!(determine_if_report_safe(&[]), Verdict::Safe);
+}
+
+#[test]
+fn drift_is_blamed_on_the_last_level() {
+    let policy = SafetyPolicy {
+        max_drift: Some(6),
+        ..SafetyPolicy::default()
+    };
+    assert_eq!(
+        policy.verdict(&[1, 3, 6, 7, 9]),
+        unsafe_at(4, Violation::Drift, Some(0))
+    );
+}
+
+proptest! {
+    #[test]
+    fn verdicts_agree_with_the_policy(
+        report in prop::collection::vec(0..15i32, 0..10),
+        policy in policy(),
+    ) {
+        let verdict = policy.verdict(&report);
+        prop_assert_eq!(verdict.is_safe(), policy.is_safe(&report));
+
+        let Verdict::Unsafe { at, violation, fixed_by } = verdict else {
+            return Ok(());
+        };
+        let steps_only = SafetyPolicy { max_drift: None, ..policy };
+        match violation {
+            Violation::Drift => {
+                prop_assert_eq!(at, report.len() - 1);
+                prop_assert!(steps_only.is_safe(&report));
+            }
+            Violation::Direction | Violation::StepSize => {
+                prop_assert!(steps_only.is_safe(&report[..at]));
+                prop_assert!(!steps_only.is_safe(&report[..=at]));
+            }
+        }
+
+        let fixes = |skip: usize| {
+            let mut rest = report.clone();
+            rest.remove(skip);
+            policy.is_safe(&rest)
+        };
+        match fixed_by {
+            Some(skip) => prop_assert!(fixes(skip)),
+            None => prop_assert!(!(0..report.len()).any(fixes)),
+        }
+    }
+}
+
+fn report(args: &[&str], stdin: &str) -> String {
+    run_aoc_with_stdin(args, stdin).unwrap().0
+}
+
+#[test]
+fn report_unsafe_prints_each_unsafe_line() {
+    let (stdout, _) =
+        run_aoc(&["run", "2", "1", "--input", DAY_02_FILE, "--report=unsafe"]).unwrap();
+    assert_eq!(
+        stdout,
+        "line 2: unsafe at index 2 (step out of range), no single removal fixes it
+line 3: unsafe at index 3 (step out of range), no single removal fixes it
+line 4: unsafe at index 2 (direction change), safe without index 1
+line 5: unsafe at index 3 (step out of range), safe without index 2
+day 2 part 1: 2
+"
+    );
+}
+
+#[test]
+fn part_2_verdicts_follow_the_dampener() {
+    let input = format!("{}\n", DAY_02);
+    assert_eq!(
+        report(&["run", "2", "2", "--report=all"], &input),
+        "line 1: safe
+line 2: unsafe at index 2 (step out of range), no single removal fixes it
+line 3: unsafe at index 3 (step out of range), no single removal fixes it
+line 4: safe without index 1 (unsafe at index 2, direction change)
+line 5: safe without index 2 (unsafe at index 3, step out of range)
+line 6: safe
+line 7: unsafe (no levels for the Problem Dampener to remove)
+day 2 part 2: 4
+"
+    );
+    assert_eq!(
+        report(&["run", "2", "2", "--report=unsafe"], "1 2 3\n\n"),
+        "line 2: unsafe (no levels for the Problem Dampener to remove)
+day 2 part 2: 1
+"
+    );
+    assert_eq!(
+        report(&["run", "2", "1", "--report=all"], "1 2 3\n\n"),
+        "line 1: safe
+line 2: safe
+day 2 part 1: 2
+"
+    );
+}
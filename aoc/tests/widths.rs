@@ -0,0 +1,87 @@
+//! Day 1 at each supported integer width, including inputs that overflow.
+
+mod common;
+
+use aoc_common::ErrorKind;
+use common::DAY_01;
+use day_01_part1::find_total_distance;
+use day_01_part2::find_similarity_score;
+
+const FAR_APART: &str = "0   2000000000\n0   2000000000\n";
+const REPEATED: &str = "2000000000   2000000000\n2000000000   2000000000\n";
+
+#[test]
+fn example_matches_at_every_width() {
+    assert_eq!(
+        find_total_distance::<i32, _>(DAY_01.as_bytes()).unwrap(),
+        11
+    );
+    assert_eq!(
+        find_total_distance::<i64, _>(DAY_01.as_bytes()).unwrap(),
+        11
+    );
+    assert_eq!(
+        find_total_distance::<u64, _>(DAY_01.as_bytes()).unwrap(),
+        11
+    );
+    assert_eq!(
+        find_total_distance::<i128, _>(DAY_01.as_bytes()).unwrap(),
+        11
+    );
+    assert_eq!(
+        find_similarity_score::<i32, _>(DAY_01.as_bytes()).unwrap(),
+        31
+    );
+    assert_eq!(
+        find_similarity_score::<i64, _>(DAY_01.as_bytes()).unwrap(),
+        31
+    );
+    assert_eq!(
+        find_similarity_score::<u64, _>(DAY_01.as_bytes()).unwrap(),
+        31
+    );
+    assert_eq!(
+        find_similarity_score::<i128, _>(DAY_01.as_bytes()).unwrap(),
+        31
+    );
+}
+
+#[test]
+fn total_distance_overflow_is_reported() {
+    let err = find_total_distance::<i32, _>(FAR_APART.as_bytes()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Overflow("total distance")));
+    assert_eq!(
+        find_total_distance::<i64, _>(FAR_APART.as_bytes()).unwrap(),
+        4_000_000_000
+    );
+}
+
+#[test]
+fn similarity_score_overflow_is_reported() {
+    let err = find_similarity_score::<i32, _>(REPEATED.as_bytes()).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::Overflow("similarity score")
+    ));
+    assert_eq!(
+        find_similarity_score::<i64, _>(REPEATED.as_bytes()).unwrap(),
+        8_000_000_000
+    );
+}
+
+#[test]
+fn distance_that_does_not_fit_the_signed_width_is_reported() {
+    let input = format!("{}   {}\n", i64::MIN, i64::MAX);
+    let err = find_total_distance::<i64, _>(input.as_bytes()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Overflow("total distance")));
+    assert_eq!(
+        find_total_distance::<i128, _>(input.as_bytes()).unwrap(),
+        u64::MAX as i128
+    );
+}
+
+#[test]
+fn negative_ids_are_rejected_when_unsigned() {
+    let err = find_total_distance::<u64, _>("-1   3\n".as_bytes()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::NotParseableAsNumber(_)));
+}
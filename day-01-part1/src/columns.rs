@@ -0,0 +1,198 @@
+use std::{fmt, io::BufRead, str::FromStr};
+
+use aoc_common::{numbered_lines, ErrorKind, SolveError};
+
+pub type RowScore = Box<dyn Fn(&[i64]) -> f64>;
+
+/// How far apart sorted columns are. Every column is compared with the first
+/// one, row by row (the i-th smallest value of each column), and the
+/// differences are combined across rows:
+///
+/// - `L1`: sum of `|v - v0|`
+/// - `L2`: square root of the sum of `(v - v0)^2`
+/// - `Chebyshev`: largest `|v - v0|`
+/// - `Spread`: sum of each row's `max - min`
+/// - `Custom`: sum of a closure over each row (a pair `[v0, v]` for a
+///   column's own distance, the whole row for the total)
+///
+/// With two columns `L1` is the puzzle's total distance.
+pub enum Metric {
+    L1,
+    L2,
+    Chebyshev,
+    Spread,
+    Custom(RowScore),
+}
+
+impl Metric {
+    pub fn custom(row_score: impl Fn(&[i64]) -> f64 + 'static) -> Self {
+        Metric::Custom(Box::new(row_score))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::L1 => "l1",
+            Metric::L2 => "l2",
+            Metric::Chebyshev => "chebyshev",
+            Metric::Spread => "spread",
+            Metric::Custom(_) => "custom",
+        }
+    }
+
+    fn accumulate(&self, total: f64, row: &[i64]) -> f64 {
+        let reference = row.first().copied().unwrap_or_default();
+        let differences = row.iter().skip(1).map(|v| v.abs_diff(reference) as f64);
+        match self {
+            Metric::L1 => total + differences.sum::<f64>(),
+            Metric::L2 => total + differences.map(|d| d * d).sum::<f64>(),
+            Metric::Chebyshev => differences.fold(total, f64::max),
+            Metric::Spread => {
+                let max = row.iter().max().copied().unwrap_or_default();
+                let min = row.iter().min().copied().unwrap_or_default();
+                total + max.abs_diff(min) as f64
+            }
+            Metric::Custom(row_score) => total + row_score(row),
+        }
+    }
+
+    fn finish(&self, total: f64) -> f64 {
+        match self {
+            Metric::L2 => total.sqrt(),
+            _ => total,
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "l1" => Ok(Metric::L1),
+            "l2" => Ok(Metric::L2),
+            "chebyshev" => Ok(Metric::Chebyshev),
+            "spread" => Ok(Metric::Spread),
+            _ => Err(format!(
+                "unknown metric '{}' (expected l1, l2, chebyshev or spread)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ColumnReport {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    /// Distance from the first column; always 0 for the first column itself.
+    pub distance: f64,
+}
+
+#[derive(Debug)]
+pub struct DistanceReport {
+    pub metric: &'static str,
+    pub rows: usize,
+    pub columns: Vec<ColumnReport>,
+    pub total: f64,
+}
+
+impl fmt::Display for DistanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "metric {} over {} rows", self.metric, self.rows)?;
+        for (i, column) in self.columns.iter().enumerate() {
+            write!(f, "column {}:", i + 1)?;
+            if let (Some(min), Some(max)) = (column.min, column.max) {
+                write!(f, " min {} max {}", min, max)?;
+            }
+            if i == 0 {
+                writeln!(f, " (reference)")?;
+            } else {
+                writeln!(f, " distance {}", column.distance)?;
+            }
+        }
+        write!(f, "total: {}", self.total)
+    }
+}
+
+pub fn find_column_distances<R: BufRead>(
+    reader: R,
+    metric: &Metric,
+) -> Result<DistanceReport, SolveError> {
+    let mut columns = parse_columns(reader)?;
+    sort_columns(&mut columns);
+    Ok(column_distances(&columns, metric))
+}
+
+/// Reads whitespace separated columns. The first line sets the number of
+/// columns and every other line must match it; blank lines are rejected
+/// wherever they are.
+pub fn parse_columns<R: BufRead>(reader: R) -> Result<Vec<Vec<i64>>, SolveError> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+    for line in numbered_lines(reader) {
+        let line = line?;
+        let values = line.numbers()?;
+        if values.is_empty() {
+            return Err(SolveError::parse("blank line").at(line.number, 1, ""));
+        }
+        if columns.is_empty() {
+            columns = vec![Vec::new(); values.len()];
+        }
+        if values.len() != columns.len() {
+            return Err(SolveError::new(ErrorKind::WrongNumValuesToUnpack {
+                expected: columns.len(),
+                found: values.len(),
+            })
+            .at(line.number, 1, line.text.trim()));
+        }
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+
+    Ok(columns)
+}
+
+pub fn sort_columns(columns: &mut [Vec<i64>]) {
+    for column in columns {
+        column.sort_unstable();
+    }
+}
+
+/// Scores already sorted, equal length columns against the first column.
+/// Each column's distance applies `metric` to the pairs `[v0, v]` of its
+/// rows; the total applies it to whole rows.
+pub fn column_distances(columns: &[Vec<i64>], metric: &Metric) -> DistanceReport {
+    let rows = columns.first().map_or(0, Vec::len);
+    let mut row = Vec::with_capacity(columns.len());
+    let mut per_column = vec![0.0; columns.len()];
+    let mut total = 0.0;
+
+    for i in 0..rows {
+        row.clear();
+        row.extend(columns.iter().map(|column| column[i]));
+        for (j, column_total) in per_column.iter_mut().enumerate().skip(1) {
+            *column_total = metric.accumulate(*column_total, &[row[0], row[j]]);
+        }
+        total = metric.accumulate(total, &row);
+    }
+
+    DistanceReport {
+        metric: metric.name(),
+        rows,
+        columns: columns
+            .iter()
+            .zip(per_column)
+            .enumerate()
+            .map(|(j, (column, column_total))| ColumnReport {
+                min: column.first().copied(),
+                max: column.last().copied(),
+                distance: if j == 0 {
+                    0.0
+                } else {
+                    metric.finish(column_total)
+                },
+            })
+            .collect(),
+        total: metric.finish(total),
+    }
+}
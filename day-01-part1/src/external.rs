@@ -0,0 +1,278 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Seek, SeekFrom, Write},
+    marker::PhantomData,
+    mem,
+    path::PathBuf,
+};
+
+use aoc_common::{
+    numbered_lines, parse_into_number_pair, Answer, CheckedInt, Solution, SolveError,
+};
+
+use crate::calculate_distance;
+
+pub const DEFAULT_RUN_LEN: usize = 1 << 20;
+pub const DEFAULT_FAN_IN: usize = 64;
+
+/// Total distance for inputs too large to hold in memory: each column is
+/// sorted in runs of `run_len` values, spilled to temp files as fixed-width
+/// binary, and merged back, never more than `fan_in` runs at a time, while
+/// the pairwise distances are summed.
+pub struct ExternalTotalDistance {
+    run_len: usize,
+    fan_in: usize,
+    temp_dir: Option<PathBuf>,
+}
+
+impl ExternalTotalDistance {
+    pub fn new(run_len: usize) -> Self {
+        Self {
+            run_len: run_len.max(1),
+            fan_in: DEFAULT_FAN_IN,
+            temp_dir: None,
+        }
+    }
+
+    pub fn in_dir(mut self, temp_dir: impl Into<PathBuf>) -> Self {
+        self.temp_dir = Some(temp_dir.into());
+        self
+    }
+
+    /// How many runs one merge may read at once (at least 2).
+    pub fn with_fan_in(mut self, fan_in: usize) -> Self {
+        self.fan_in = fan_in.max(2);
+        self
+    }
+
+    fn spill_file(&self) -> std::io::Result<File> {
+        match &self.temp_dir {
+            Some(dir) => tempfile::tempfile_in(dir),
+            None => tempfile::tempfile(),
+        }
+    }
+}
+
+impl Default for ExternalTotalDistance {
+    fn default() -> Self {
+        Self::new(DEFAULT_RUN_LEN)
+    }
+}
+
+impl Solution for ExternalTotalDistance {
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+    const NAME: &'static str = "total distance (external sort)";
+    const STREAMS_INPUT: bool = true;
+
+    type Parsed = (SortedRuns<i64>, SortedRuns<i64>);
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        spill_sorted_runs(input, self.run_len, self.fan_in, &|| self.spill_file())
+    }
+
+    fn compute(&self, (a_runs, b_runs): &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(total_distance_of_runs(a_runs, b_runs)?.into())
+    }
+}
+
+pub fn find_total_distance_external<T: CheckedInt, R: BufRead>(
+    reader: R,
+    run_len: usize,
+) -> Result<T, SolveError> {
+    let (a_runs, b_runs) =
+        spill_sorted_runs(reader, run_len.max(1), DEFAULT_FAN_IN, &tempfile::tempfile)?;
+    total_distance_of_runs(&a_runs, &b_runs)
+}
+
+/// One column of the input, as sorted runs in anonymous temp files that are
+/// removed when dropped.
+///
+/// Runs are kept in levels: once a level holds `fan_in` runs they are merged
+/// into one run on the level above, so each value is rewritten once per
+/// level and no merge reads more than `fan_in` runs.
+pub struct SortedRuns<T> {
+    levels: Vec<Vec<File>>,
+    fan_in: usize,
+    _values: PhantomData<T>,
+}
+
+impl<T: CheckedInt> SortedRuns<T> {
+    fn new(fan_in: usize) -> Self {
+        Self {
+            levels: Vec::new(),
+            fan_in: fan_in.max(2),
+            _values: PhantomData,
+        }
+    }
+
+    pub fn num_runs(&self) -> usize {
+        self.levels.iter().map(Vec::len).sum()
+    }
+
+    fn spill(
+        &mut self,
+        values: &mut Vec<T>,
+        spill_file: &dyn Fn() -> std::io::Result<File>,
+    ) -> Result<(), SolveError> {
+        values.sort_unstable();
+        let run = write_run(values.drain(..).map(Ok), spill_file)?;
+        self.add_run(0, run, spill_file)
+    }
+
+    fn add_run(
+        &mut self,
+        mut level: usize,
+        mut run: File,
+        spill_file: &dyn Fn() -> std::io::Result<File>,
+    ) -> Result<(), SolveError> {
+        loop {
+            if self.levels.len() == level {
+                self.levels.push(Vec::new());
+            }
+            self.levels[level].push(run);
+            if self.levels[level].len() < self.fan_in {
+                return Ok(());
+            }
+
+            let full = mem::take(&mut self.levels[level]);
+            run = write_run(MergedRuns::<T>::new(&full)?, spill_file)?;
+            level += 1;
+        }
+    }
+
+    /// Merges the lowest levels upwards until `merged` has at most `fan_in`
+    /// runs left to read.
+    fn finish(&mut self, spill_file: &dyn Fn() -> std::io::Result<File>) -> Result<(), SolveError> {
+        let mut level = 0;
+        while self.num_runs() > self.fan_in {
+            let mut runs = mem::take(&mut self.levels[level]);
+            let run = match runs.len() {
+                0 => {
+                    level += 1;
+                    continue;
+                }
+                1 => runs.remove(0),
+                _ => write_run(MergedRuns::<T>::new(&runs)?, spill_file)?,
+            };
+            self.add_run(level + 1, run, spill_file)?;
+            level += 1;
+        }
+        Ok(())
+    }
+
+    /// Every value across all runs in ascending order.
+    pub fn merged(&self) -> Result<MergedRuns<'_, T>, SolveError> {
+        MergedRuns::new(self.levels.iter().flatten())
+    }
+}
+
+pub struct MergedRuns<'a, T> {
+    readers: Vec<BufReader<&'a File>>,
+    heap: BinaryHeap<Reverse<(T, usize)>>,
+    buf: Vec<u8>,
+}
+
+impl<'a, T: CheckedInt> MergedRuns<'a, T> {
+    fn new(runs: impl IntoIterator<Item = &'a File>) -> Result<Self, SolveError> {
+        let mut merged = Self {
+            readers: Vec::new(),
+            heap: BinaryHeap::new(),
+            buf: vec![0; T::WIDTH],
+        };
+        for (index, mut file) in runs.into_iter().enumerate() {
+            file.seek(SeekFrom::Start(0))?;
+            let mut reader = BufReader::new(file);
+            if let Some(value) = read_value(&mut reader, &mut merged.buf)? {
+                merged.heap.push(Reverse((value, index)));
+            }
+            merged.readers.push(reader);
+        }
+        Ok(merged)
+    }
+}
+
+impl<T: CheckedInt> Iterator for MergedRuns<'_, T> {
+    type Item = Result<T, SolveError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((value, index)) = self.heap.pop()?;
+        match read_value(&mut self.readers[index], &mut self.buf) {
+            Ok(Some(next)) => self.heap.push(Reverse((next, index))),
+            Ok(None) => {}
+            Err(e) => return Some(Err(e)),
+        }
+        Some(Ok(value))
+    }
+}
+
+pub fn spill_sorted_runs<T: CheckedInt, R: BufRead>(
+    reader: R,
+    run_len: usize,
+    fan_in: usize,
+    spill_file: &dyn Fn() -> std::io::Result<File>,
+) -> Result<(SortedRuns<T>, SortedRuns<T>), SolveError> {
+    let mut a_runs = SortedRuns::new(fan_in);
+    let mut b_runs = SortedRuns::new(fan_in);
+    let mut a_buffer = Vec::with_capacity(run_len);
+    let mut b_buffer = Vec::with_capacity(run_len);
+
+    for pair in numbered_lines(reader).map(parse_into_number_pair) {
+        let (a, b) = pair?;
+        a_buffer.push(a);
+        b_buffer.push(b);
+        if a_buffer.len() == run_len {
+            a_runs.spill(&mut a_buffer, spill_file)?;
+            b_runs.spill(&mut b_buffer, spill_file)?;
+        }
+    }
+    if !a_buffer.is_empty() {
+        a_runs.spill(&mut a_buffer, spill_file)?;
+        b_runs.spill(&mut b_buffer, spill_file)?;
+    }
+    a_runs.finish(spill_file)?;
+    b_runs.finish(spill_file)?;
+
+    Ok((a_runs, b_runs))
+}
+
+pub fn total_distance_of_runs<T: CheckedInt>(
+    a_runs: &SortedRuns<T>,
+    b_runs: &SortedRuns<T>,
+) -> Result<T, SolveError> {
+    a_runs
+        .merged()?
+        .zip(b_runs.merged()?)
+        .try_fold(T::default(), |total, (a, b)| {
+            calculate_distance((a?, b?))
+                .and_then(|distance| total.checked_add(distance))
+                .ok_or_else(|| SolveError::overflow("total distance"))
+        })
+}
+
+/// Writes sorted `values` to a new spill file, `T::WIDTH` bytes each.
+fn write_run<T: CheckedInt>(
+    values: impl Iterator<Item = Result<T, SolveError>>,
+    spill_file: &dyn Fn() -> std::io::Result<File>,
+) -> Result<File, SolveError> {
+    let mut writer = BufWriter::new(spill_file()?);
+    let mut buf = vec![0; T::WIDTH];
+    for value in values {
+        value?.write_le(&mut buf);
+        writer.write_all(&buf)?;
+    }
+    Ok(writer.into_inner().map_err(|e| e.into_error())?)
+}
+
+fn read_value<T: CheckedInt, R: BufRead>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> Result<Option<T>, SolveError> {
+    if reader.fill_buf()?.is_empty() {
+        return Ok(None);
+    }
+    reader.read_exact(buf)?;
+    Ok(Some(T::read_le(buf)))
+}
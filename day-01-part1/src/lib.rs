@@ -0,0 +1,131 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use aoc_common::{Answer, CheckedInt, NumberScanner, Solution, SolveError};
+
+mod columns;
+mod external;
+mod parallel;
+mod streaming;
+
+pub use columns::{
+    column_distances, find_column_distances, parse_columns, sort_columns, ColumnReport,
+    DistanceReport, Metric, RowScore,
+};
+pub use external::{
+    find_total_distance_external, spill_sorted_runs, total_distance_of_runs, ExternalTotalDistance,
+    MergedRuns, SortedRuns, DEFAULT_FAN_IN, DEFAULT_RUN_LEN,
+};
+pub use parallel::{
+    find_total_distance_parallel, parse_sorted_columns, sum_sorted_distances, ParallelTotalDistance,
+};
+pub use streaming::StreamingLists;
+
+pub struct TotalDistance;
+
+impl Solution for TotalDistance {
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+    const NAME: &'static str = "total distance";
+
+    type Parsed = (Vec<i64>, Vec<i64>);
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        parse_location_lists(input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        parse_location_list_bytes(input)
+    }
+
+    fn compute(&self, (a_list, b_list): &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(total_distance(a_list, b_list)?.into())
+    }
+}
+
+pub fn find_total_distance<T: CheckedInt, R: BufRead>(reader: R) -> Result<T, SolveError> {
+    let (a_list, b_list) = parse_location_lists(reader)?;
+    total_distance(&a_list, &b_list)
+}
+
+pub fn parse_location_lists<T: CheckedInt, R: BufRead>(
+    mut reader: R,
+) -> Result<(Vec<T>, Vec<T>), SolveError> {
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input)?;
+    parse_location_list_bytes(&input)
+}
+
+pub fn parse_location_list_bytes<T: CheckedInt>(
+    input: &[u8],
+) -> Result<(Vec<T>, Vec<T>), SolveError> {
+    let mut scanner = NumberScanner::new(input);
+    std::iter::from_fn(|| scanner.read_pair()).try_fold((Vec::new(), Vec::new()), list_accum)
+}
+
+pub fn total_distance<T: CheckedInt>(a_list: &[T], b_list: &[T]) -> Result<T, SolveError> {
+    let mut a_heap: BinaryHeap<Reverse<T>> = a_list.iter().copied().map(Reverse).collect();
+    let mut b_heap: BinaryHeap<Reverse<T>> = b_list.iter().copied().map(Reverse).collect();
+
+    let a_sorted = std::iter::from_fn(|| a_heap.pop().map(|a| a.0));
+    let b_sorted = std::iter::from_fn(|| b_heap.pop().map(|b| b.0));
+    a_sorted
+        .zip(b_sorted)
+        .try_fold(T::default(), |total, pair| {
+            calculate_distance(pair)
+                .and_then(|distance| total.checked_add(distance))
+                .ok_or_else(|| SolveError::overflow("total distance"))
+        })
+}
+
+/// One row of the sorted pairing behind the total distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairDistance<T> {
+    pub left: T,
+    pub right: T,
+    pub distance: T,
+}
+
+/// Every sorted pair and its distance, in the order they are summed.
+pub fn explain_total_distance<T: CheckedInt>(
+    a_list: &[T],
+    b_list: &[T],
+) -> Result<Vec<PairDistance<T>>, SolveError> {
+    let mut a_sorted = a_list.to_vec();
+    let mut b_sorted = b_list.to_vec();
+    a_sorted.sort_unstable();
+    b_sorted.sort_unstable();
+
+    a_sorted
+        .into_iter()
+        .zip(b_sorted)
+        .map(|(left, right)| {
+            calculate_distance((left, right))
+                .map(|distance| PairDistance {
+                    left,
+                    right,
+                    distance,
+                })
+                .ok_or_else(|| SolveError::overflow("total distance"))
+        })
+        .collect()
+}
+
+fn list_accum<T>(
+    lists: (Vec<T>, Vec<T>),
+    next_item: Result<(T, T), SolveError>,
+) -> Result<(Vec<T>, Vec<T>), SolveError> {
+    match (next_item, lists) {
+        (Ok((a, b)), (mut a_list, mut b_list)) => {
+            a_list.push(a);
+            b_list.push(b);
+            Ok((a_list, b_list))
+        }
+        (Err(e), _) => Err(e),
+    }
+}
+
+fn calculate_distance<T: CheckedInt>((a, b): (T, T)) -> Option<T> {
+    a.checked_abs_diff(b)
+}
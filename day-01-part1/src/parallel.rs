@@ -0,0 +1,85 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    parallel::{clamp_threads, par_checked_sum, par_sort, parse_chunks},
+    Answer, CheckedInt, Solution, SolveError,
+};
+
+use crate::{calculate_distance, parse_location_list_bytes, total_distance};
+
+/// Total distance with the input parsed in line-aligned chunks, both columns
+/// sorted, and the distances summed, all across `threads` threads. Gives the
+/// same answer (or error) as `TotalDistance`.
+pub struct ParallelTotalDistance {
+    threads: usize,
+}
+
+impl ParallelTotalDistance {
+    /// `threads` is clamped by `clamp_threads`.
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads: clamp_threads(threads),
+        }
+    }
+}
+
+impl Solution for ParallelTotalDistance {
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+    const NAME: &'static str = "total distance (parallel)";
+
+    /// Both columns, already sorted.
+    type Parsed = (Vec<i64>, Vec<i64>);
+
+    fn parse<R: BufRead>(&self, mut input: R) -> Result<Self::Parsed, SolveError> {
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+        self.parse_bytes(&bytes)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        parse_sorted_columns(input, self.threads)
+    }
+
+    fn compute(&self, (a_sorted, b_sorted): &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(sum_sorted_distances(a_sorted, b_sorted, self.threads)?.into())
+    }
+}
+
+pub fn find_total_distance_parallel<T: CheckedInt + Send + Sync>(
+    input: &[u8],
+    threads: usize,
+) -> Result<T, SolveError> {
+    let (a_sorted, b_sorted) = parse_sorted_columns(input, threads)?;
+    sum_sorted_distances(&a_sorted, &b_sorted, threads)
+}
+
+pub fn parse_sorted_columns<T: CheckedInt + Send + Sync>(
+    input: &[u8],
+    threads: usize,
+) -> Result<(Vec<T>, Vec<T>), SolveError> {
+    let chunks = parse_chunks(input, threads, parse_location_list_bytes::<T>)?;
+    let (mut a_list, mut b_list): (Vec<T>, Vec<T>) = (Vec::new(), Vec::new());
+    for (a_chunk, b_chunk) in chunks {
+        a_list.extend(a_chunk);
+        b_list.extend(b_chunk);
+    }
+
+    par_sort(&mut a_list, threads)?;
+    par_sort(&mut b_list, threads)?;
+    Ok((a_list, b_list))
+}
+
+pub fn sum_sorted_distances<T: CheckedInt + Send + Sync>(
+    a_sorted: &[T],
+    b_sorted: &[T],
+    threads: usize,
+) -> Result<T, SolveError> {
+    let len = a_sorted.len().min(b_sorted.len());
+    match par_checked_sum(len, threads, |i| {
+        calculate_distance((a_sorted[i], b_sorted[i]))
+    }) {
+        Some(total) => Ok(total),
+        None => total_distance(a_sorted, b_sorted),
+    }
+}
@@ -0,0 +1,280 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use aoc_common::OccurrenceCounter;
+
+/// Day 1 answers kept up to date as pairs arrive one at a time.
+///
+/// The total distance of the sorted pairing is the area between the two
+/// columns' counting functions: with `depth(x)` the number of left values
+/// `<= x` minus the number of right values `<= x`, it is the sum of
+/// `|depth(x)|` over every integer `x`. A new pair `(a, b)` raises `depth` by
+/// one on `a..b` (or lowers it on `b..a`) and leaves it alone elsewhere, so
+/// only that stretch needs re-scoring. `DepthProfile` does that in O(√n) for
+/// n pairs seen so far, rather than shifting sorted columns around in O(n).
+/// The similarity score only changes by the new values' contribution, which
+/// comes from the occurrence counts.
+#[derive(Debug, Default)]
+pub struct StreamingLists {
+    depth: DepthProfile,
+    left_counts: OccurrenceCounter<i64>,
+    right_counts: OccurrenceCounter<i64>,
+    len: usize,
+    total_distance: i128,
+    similarity_score: i128,
+}
+
+impl StreamingLists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, a: i64, b: i64) {
+        self.depth.insert(a);
+        self.depth.insert(b);
+        self.total_distance += match a.cmp(&b) {
+            Ordering::Less => self.depth.shift(a, b, 1),
+            Ordering::Greater => self.depth.shift(b, a, -1),
+            Ordering::Equal => 0,
+        };
+        self.len += 1;
+
+        self.left_counts.increment_observed_count(a);
+        self.similarity_score += i128::from(a) * self.right_counts.num_occurances(&a) as i128;
+        self.right_counts.increment_observed_count(b);
+        self.similarity_score += i128::from(b) * self.left_counts.num_occurances(&b) as i128;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn total_distance(&self) -> i128 {
+        self.total_distance
+    }
+
+    pub fn similarity_score(&self) -> i128 {
+        self.similarity_score
+    }
+}
+
+impl Extend<(i64, i64)> for StreamingLists {
+    fn extend<I: IntoIterator<Item = (i64, i64)>>(&mut self, pairs: I) {
+        for (a, b) in pairs {
+            self.push(a, b);
+        }
+    }
+}
+
+const MIN_BLOCK_LEN: usize = 16;
+
+/// `depth` as segments, each running from one value seen so far up to the
+/// next, in blocks of about √n segments. Shifting a whole block only moves its
+/// `offset`; a partly covered block is rescanned. Outside the values seen so
+/// far `depth` is 0, so the last segment has no width.
+#[derive(Debug, Default)]
+struct DepthProfile {
+    blocks: Vec<Block>,
+    segments: usize,
+    block_len: usize,
+    rebalanced_at: usize,
+}
+
+impl DepthProfile {
+    /// Makes `value` the start of a segment. Both halves of a split segment
+    /// keep its depth, so the total distance doesn't change.
+    fn insert(&mut self, value: i64) {
+        if self.blocks.is_empty() {
+            self.blocks.push(Block::new(vec![Segment {
+                start: value,
+                width: 0,
+                depth: 0,
+            }]));
+            self.segments = 1;
+            self.rebalance();
+            return;
+        }
+
+        let index = self.block_of(value);
+        let block = &mut self.blocks[index];
+        let at = block.segments.partition_point(|s| s.start <= value);
+        let segment = match at.checked_sub(1).map(|i| &mut block.segments[i]) {
+            Some(split) if split.start == value => return,
+            Some(split) => {
+                let width = i128::from(value) - i128::from(split.start);
+                let rest = if split.width == 0 {
+                    0
+                } else {
+                    split.width - width
+                };
+                split.width = width;
+                Segment {
+                    start: value,
+                    width: rest,
+                    depth: split.depth,
+                }
+            }
+            None => Segment {
+                start: value,
+                width: i128::from(block.first()) - i128::from(value),
+                depth: -block.offset,
+            },
+        };
+        block.segments.insert(at, segment);
+        block.rebuild();
+        self.segments += 1;
+
+        if block.segments.len() > 2 * self.block_len {
+            let tail = block.segments.split_off(block.segments.len() / 2);
+            block.rebuild();
+            self.blocks.insert(index + 1, Block::new(tail));
+        }
+        if self.segments >= 2 * self.rebalanced_at {
+            self.rebalance();
+        }
+    }
+
+    /// Adds `by` (1 or -1) to `depth` on `lo..hi`, both already segment
+    /// starts, and returns how much the total distance changes.
+    fn shift(&mut self, lo: i64, hi: i64, by: i64) -> i128 {
+        let (first, last) = (self.block_of(lo), self.block_of(hi));
+        self.blocks[first..=last]
+            .iter_mut()
+            .map(|block| {
+                if lo <= block.first() && block.last() < hi {
+                    block.shift(by)
+                } else {
+                    block.shift_within(lo, hi, by)
+                }
+            })
+            .sum()
+    }
+
+    /// The block holding the segment `value` falls in, or the first block for
+    /// values below every segment.
+    fn block_of(&self, value: i64) -> usize {
+        self.blocks
+            .partition_point(|block| block.first() <= value)
+            .saturating_sub(1)
+    }
+
+    /// Regroups the segments into blocks of √n, whenever n has doubled, so
+    /// blocks split while n was small don't pile up.
+    fn rebalance(&mut self) {
+        self.block_len = self.segments.isqrt().max(MIN_BLOCK_LEN);
+        self.rebalanced_at = self.segments;
+        let segments: Vec<Segment> = self
+            .blocks
+            .drain(..)
+            .flat_map(|mut block| {
+                block.rebuild();
+                block.segments
+            })
+            .collect();
+        self.blocks = segments
+            .chunks(self.block_len)
+            .map(|chunk| Block::new(chunk.to_vec()))
+            .collect();
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Segment {
+    start: i64,
+    width: i128,
+    depth: i64,
+}
+
+/// A run of segments whose depths are all `offset` more than stored.
+/// `widths` totals the width at each stored depth, which is what shifting the
+/// whole block needs to keep `negative` and `positive` (the width at which
+/// `depth` is below or above 0) current.
+#[derive(Debug, Default)]
+struct Block {
+    segments: Vec<Segment>,
+    offset: i64,
+    widths: HashMap<i64, i128>,
+    width: i128,
+    negative: i128,
+    positive: i128,
+}
+
+impl Block {
+    fn new(segments: Vec<Segment>) -> Self {
+        let mut block = Self {
+            segments,
+            ..Self::default()
+        };
+        block.rebuild();
+        block
+    }
+
+    fn first(&self) -> i64 {
+        self.segments[0].start
+    }
+
+    fn last(&self) -> i64 {
+        self.segments[self.segments.len() - 1].start
+    }
+
+    /// Folds `offset` into the segments and recounts the widths.
+    fn rebuild(&mut self) {
+        self.widths.clear();
+        self.width = 0;
+        self.negative = 0;
+        self.positive = 0;
+        for segment in &mut self.segments {
+            segment.depth += self.offset;
+            *self.widths.entry(segment.depth).or_default() += segment.width;
+            self.width += segment.width;
+            match segment.depth.cmp(&0) {
+                Ordering::Less => self.negative += segment.width,
+                Ordering::Greater => self.positive += segment.width,
+                Ordering::Equal => {}
+            }
+        }
+        self.offset = 0;
+    }
+
+    fn width_at(&self, depth: i64) -> i128 {
+        self.widths
+            .get(&(depth - self.offset))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Adds `by` to every segment's depth. Moving towards 0 shrinks
+    /// `|depth|` by one, anything else grows it.
+    fn shift(&mut self, by: i64) -> i128 {
+        let change = if by > 0 {
+            let change = self.width - 2 * self.negative;
+            self.negative -= self.width_at(-1);
+            self.positive += self.width_at(0);
+            change
+        } else {
+            let change = self.width - 2 * self.positive;
+            self.positive -= self.width_at(1);
+            self.negative += self.width_at(0);
+            change
+        };
+        self.offset += by;
+        change
+    }
+
+    /// Like `shift`, for only the segments starting in `lo..hi`.
+    fn shift_within(&mut self, lo: i64, hi: i64, by: i64) -> i128 {
+        let mut change = 0;
+        for segment in &mut self.segments {
+            if (lo..hi).contains(&segment.start) {
+                let depth = segment.depth + self.offset;
+                change += segment.width * i128::from((depth + by).abs() - depth.abs());
+                segment.depth += by;
+            }
+        }
+        self.rebuild();
+        change
+    }
+}
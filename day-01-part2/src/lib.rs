@@ -0,0 +1,120 @@
+use std::io::BufRead;
+
+use aoc_common::{Answer, CheckedInt, NumberScanner, Solution, SolveError};
+
+mod parallel;
+mod variants;
+
+pub use aoc_common::OccurrenceCounter;
+pub use parallel::{
+    find_similarity_score_parallel, parse_left_and_right_runs, similarity_score_from_runs,
+    ParallelSimilarityScore, Runs,
+};
+pub use variants::{
+    cosine_similarity, find_cosine_similarity, find_symmetric_similarity_score,
+    find_weighted_jaccard, parse_histograms, symmetric_similarity_score, weighted_jaccard,
+};
+
+pub struct SimilarityScore;
+
+impl Solution for SimilarityScore {
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+    const NAME: &'static str = "similarity score";
+
+    type Parsed = (Vec<i64>, OccurrenceCounter<i64>);
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        parse_left_list_and_right_counts(input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        parse_left_list_and_right_count_bytes(input)
+    }
+
+    fn compute(&self, (left_list, right_counts): &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(similarity_score(left_list, right_counts)?.into())
+    }
+}
+
+pub fn find_similarity_score<T: CheckedInt, R: BufRead>(reader: R) -> Result<T, SolveError> {
+    let (left_list, right_occurence_counter) = parse_left_list_and_right_counts(reader)?;
+    similarity_score(&left_list, &right_occurence_counter)
+}
+
+pub fn parse_left_list_and_right_counts<T: CheckedInt, R: BufRead>(
+    mut reader: R,
+) -> Result<(Vec<T>, OccurrenceCounter<T>), SolveError> {
+    let mut input = Vec::new();
+    reader.read_to_end(&mut input)?;
+    parse_left_list_and_right_count_bytes(&input)
+}
+
+pub fn parse_left_list_and_right_count_bytes<T: CheckedInt>(
+    input: &[u8],
+) -> Result<(Vec<T>, OccurrenceCounter<T>), SolveError> {
+    let left_list: Vec<T> = Vec::new();
+    let right_occurrence_counter: OccurrenceCounter<T> = OccurrenceCounter::new();
+
+    let mut scanner = NumberScanner::new(input);
+    std::iter::from_fn(|| scanner.read_pair()).try_fold(
+        (left_list, right_occurrence_counter),
+        accum_into_left_list_and_right_map,
+    )
+}
+
+pub fn similarity_score<T: CheckedInt>(
+    left_list: &[T],
+    right_occurence_counter: &OccurrenceCounter<T>,
+) -> Result<T, SolveError> {
+    left_list.iter().try_fold(T::default(), |total, left| {
+        T::from_count(right_occurence_counter.num_occurances(left))
+            .and_then(|count| left.checked_mul(count))
+            .and_then(|score| total.checked_add(score))
+            .ok_or_else(|| SolveError::overflow("similarity score"))
+    })
+}
+
+/// One left value's contribution to the similarity score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeftScore<T> {
+    pub left: T,
+    pub occurrences: usize,
+    pub score: T,
+}
+
+/// Every left value, in input order, with how often it appears on the right
+/// and the product that is added to the score.
+pub fn explain_similarity_score<T: CheckedInt>(
+    left_list: &[T],
+    right_occurence_counter: &OccurrenceCounter<T>,
+) -> Result<Vec<LeftScore<T>>, SolveError> {
+    left_list
+        .iter()
+        .map(|&left| {
+            let occurrences = right_occurence_counter.num_occurances(&left);
+            T::from_count(occurrences)
+                .and_then(|count| left.checked_mul(count))
+                .map(|score| LeftScore {
+                    left,
+                    occurrences,
+                    score,
+                })
+                .ok_or_else(|| SolveError::overflow("similarity score"))
+        })
+        .collect()
+}
+
+fn accum_into_left_list_and_right_map<T: Ord>(
+    accum: (Vec<T>, OccurrenceCounter<T>),
+    next_item: Result<(T, T), SolveError>,
+) -> Result<(Vec<T>, OccurrenceCounter<T>), SolveError> {
+    match (next_item, accum) {
+        (Ok((left, right)), (mut left_list, mut right_counts)) => {
+            left_list.push(left);
+            right_counts.increment_observed_count(right);
+            Ok((left_list, right_counts))
+        }
+        (Err(e), _) => Err(e),
+    }
+}
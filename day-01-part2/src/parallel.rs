@@ -0,0 +1,106 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    parallel::{clamp_threads, par_checked_sum, par_sort, parse_chunks},
+    Answer, CheckedInt, NumberScanner, Solution, SolveError,
+};
+
+/// Sorted `(value, count)` pairs, one per distinct value.
+pub type Runs<T> = Vec<(T, usize)>;
+
+/// Similarity score with the input parsed in line-aligned chunks, the right
+/// column sorted into runs of equal values, and each left value's occurrences
+/// looked up and summed, all across `threads` threads. Gives the same answer (or error) as
+/// `SimilarityScore`.
+pub struct ParallelSimilarityScore {
+    threads: usize,
+}
+
+impl ParallelSimilarityScore {
+    /// `threads` is clamped by `clamp_threads`.
+    pub fn new(threads: usize) -> Self {
+        Self {
+            threads: clamp_threads(threads),
+        }
+    }
+}
+
+impl Solution for ParallelSimilarityScore {
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+    const NAME: &'static str = "similarity score (parallel)";
+
+    /// The left column in input order and the right column's runs.
+    type Parsed = (Vec<i64>, Runs<i64>);
+
+    fn parse<R: BufRead>(&self, mut input: R) -> Result<Self::Parsed, SolveError> {
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+        self.parse_bytes(&bytes)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        parse_left_and_right_runs(input, self.threads)
+    }
+
+    fn compute(&self, (left_list, right_runs): &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(similarity_score_from_runs(left_list, right_runs, self.threads)?.into())
+    }
+}
+
+pub fn find_similarity_score_parallel<T: CheckedInt + Send + Sync>(
+    input: &[u8],
+    threads: usize,
+) -> Result<T, SolveError> {
+    let (left_list, right_runs) = parse_left_and_right_runs(input, threads)?;
+    similarity_score_from_runs(&left_list, &right_runs, threads)
+}
+
+pub fn parse_left_and_right_runs<T: CheckedInt + Send + Sync>(
+    input: &[u8],
+    threads: usize,
+) -> Result<(Vec<T>, Runs<T>), SolveError> {
+    let chunks = parse_chunks(input, threads, |chunk| {
+        let mut scanner = NumberScanner::new(chunk);
+        std::iter::from_fn(|| scanner.read_pair()).collect::<Result<Vec<(T, T)>, SolveError>>()
+    })?;
+    let (mut left_list, mut right_list): (Vec<T>, Vec<T>) = (Vec::new(), Vec::new());
+    for pairs in chunks {
+        for (left, right) in pairs {
+            left_list.push(left);
+            right_list.push(right);
+        }
+    }
+
+    par_sort(&mut right_list, threads)?;
+    let right_runs = right_list
+        .chunk_by(|a, b| a == b)
+        .map(|run| (run[0], run.len()))
+        .collect();
+    Ok((left_list, right_runs))
+}
+
+pub fn similarity_score_from_runs<T: CheckedInt + Send + Sync>(
+    left_list: &[T],
+    right_runs: &[(T, usize)],
+    threads: usize,
+) -> Result<T, SolveError> {
+    let score = |left: T| {
+        let count = match right_runs.binary_search_by_key(&left, |&(value, _)| value) {
+            Ok(run) => right_runs[run].1,
+            Err(_) => 0,
+        };
+        T::from_count(count).and_then(|count| left.checked_mul(count))
+    };
+
+    match par_checked_sum(left_list.len(), threads, |i| score(left_list[i])) {
+        Some(total) => Ok(total),
+        // Redo the sum in input order so the error matches the sequential
+        // solver's.
+        None => left_list.iter().try_fold(T::default(), |total, &left| {
+            score(left)
+                .and_then(|score| total.checked_add(score))
+                .ok_or_else(|| SolveError::overflow("similarity score"))
+        }),
+    }
+}
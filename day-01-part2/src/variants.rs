@@ -0,0 +1,99 @@
+use std::io::BufRead;
+
+use aoc_common::{numbered_lines, parse_into_number_pair, CheckedInt, SolveError};
+
+use crate::OccurrenceCounter;
+
+pub fn find_symmetric_similarity_score<T: CheckedInt, R: BufRead>(
+    reader: R,
+) -> Result<T, SolveError> {
+    let (left_counts, right_counts) = parse_histograms(reader)?;
+    symmetric_similarity_score(&left_counts, &right_counts)
+}
+
+pub fn find_weighted_jaccard<R: BufRead>(reader: R) -> Result<f64, SolveError> {
+    let (left_counts, right_counts) = parse_histograms::<i64, _>(reader)?;
+    Ok(weighted_jaccard(&left_counts, &right_counts))
+}
+
+pub fn find_cosine_similarity<R: BufRead>(reader: R) -> Result<f64, SolveError> {
+    let (left_counts, right_counts) = parse_histograms::<i64, _>(reader)?;
+    Ok(cosine_similarity(&left_counts, &right_counts))
+}
+
+/// Both columns as histograms of how often each location ID appears.
+pub fn parse_histograms<T: CheckedInt, R: BufRead>(
+    reader: R,
+) -> Result<(OccurrenceCounter<T>, OccurrenceCounter<T>), SolveError> {
+    let mut left_counts = OccurrenceCounter::new();
+    let mut right_counts = OccurrenceCounter::new();
+    for pair in numbered_lines(reader).map(parse_into_number_pair) {
+        let (left, right) = pair?;
+        left_counts.increment_observed_count(left);
+        right_counts.increment_observed_count(right);
+    }
+
+    Ok((left_counts, right_counts))
+}
+
+/// Each left value times how often it appears on the right, plus each right
+/// value times how often it appears on the left.
+pub fn symmetric_similarity_score<T: CheckedInt>(
+    left_counts: &OccurrenceCounter<T>,
+    right_counts: &OccurrenceCounter<T>,
+) -> Result<T, SolveError> {
+    let overflow = || SolveError::overflow("symmetric similarity score");
+    let one_way = |from: &OccurrenceCounter<T>, to: &OccurrenceCounter<T>| {
+        from.iter().try_fold(T::default(), |total, (n, count)| {
+            count
+                .checked_mul(to.num_occurances(n))
+                .and_then(T::from_count)
+                .and_then(|weight| n.checked_mul(weight))
+                .and_then(|score| total.checked_add(score))
+                .ok_or_else(overflow)
+        })
+    };
+
+    one_way(left_counts, right_counts)?
+        .checked_add(one_way(right_counts, left_counts)?)
+        .ok_or_else(overflow)
+}
+
+/// Sum of the smaller count over sum of the larger count, for every value in
+/// either column. Two empty columns are identical, so score 1.
+pub fn weighted_jaccard<T: Ord + Clone>(
+    left_counts: &OccurrenceCounter<T>,
+    right_counts: &OccurrenceCounter<T>,
+) -> f64 {
+    let smaller = left_counts.intersection(right_counts).len();
+    let larger = left_counts.len() + right_counts.len() - smaller;
+    if larger == 0 {
+        return 1.0;
+    }
+    smaller as f64 / larger as f64
+}
+
+/// Cosine of the angle between the two histograms viewed as count vectors.
+/// An empty column has no direction, so scores 0.
+pub fn cosine_similarity<T: Ord>(
+    left_counts: &OccurrenceCounter<T>,
+    right_counts: &OccurrenceCounter<T>,
+) -> f64 {
+    let dot: f64 = left_counts
+        .iter()
+        .map(|(n, count)| count as f64 * right_counts.num_occurances(n) as f64)
+        .sum();
+    let norm = |counts: &OccurrenceCounter<T>| {
+        counts
+            .iter()
+            .map(|(_, count)| (count as f64).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    };
+
+    let norms = norm(left_counts) * norm(right_counts);
+    if norms == 0.0 {
+        return 0.0;
+    }
+    dot / norms
+}
@@ -0,0 +1,86 @@
+use std::io::BufRead;
+
+use aoc_common::{Answer, SafetyPolicy, Solution, SolveError, Verdict};
+
+pub use aoc_common::reports::{parse_report_bytes, parse_reports};
+
+pub struct SafeReports;
+
+impl Solution for SafeReports {
+    const DAY: u8 = 2;
+    const PART: u8 = 1;
+    const NAME: &'static str = "safe reports";
+
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        parse_reports(input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        parse_report_bytes(input)
+    }
+
+    fn compute(&self, reports: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(count_safe_reports(reports).into())
+    }
+}
+
+/// Safe reports under a rule other than the puzzle's.
+pub struct PolicySafeReports {
+    policy: SafetyPolicy,
+}
+
+impl PolicySafeReports {
+    pub fn new(policy: SafetyPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl Solution for PolicySafeReports {
+    const DAY: u8 = 2;
+    const PART: u8 = 1;
+    const NAME: &'static str = "safe reports (custom policy)";
+
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        parse_reports(input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        parse_report_bytes(input)
+    }
+
+    fn compute(&self, reports: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(count_safe_reports_under(reports, &self.policy).into())
+    }
+}
+
+pub fn find_num_safe_reports<R: BufRead>(reader: R) -> Result<i32, SolveError> {
+    let reports = parse_reports(reader)?;
+    Ok(count_safe_reports(&reports))
+}
+
+pub fn count_safe_reports(reports: &[Vec<i32>]) -> i32 {
+    count_safe_reports_under(reports, &SafetyPolicy::default())
+}
+
+pub fn count_safe_reports_under(reports: &[Vec<i32>], policy: &SafetyPolicy) -> i32 {
+    reports
+        .iter()
+        .map(|report| policy.is_safe(report))
+        .fold(0, sum_safe_reports)
+}
+
+pub fn determine_if_report_safe(report: &[i32]) -> Verdict {
+    SafetyPolicy::default().verdict(report)
+}
+
+fn sum_safe_reports(accum: i32, is_safe: bool) -> i32 {
+    if is_safe {
+        accum + 1
+    } else {
+        accum
+    }
+}
@@ -0,0 +1,102 @@
+use std::io::BufRead;
+
+use aoc_common::{Answer, SafetyPolicy, Solution, SolveError};
+
+pub use aoc_common::reports::{parse_report_bytes, parse_reports};
+
+mod removals;
+mod stats;
+
+pub use removals::{fewest_removals, removal_histogram, RemovalHistogram};
+pub use stats::{report_stats, ReportStats, Shape};
+
+pub struct DampenedSafeReports;
+
+impl Solution for DampenedSafeReports {
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+    const NAME: &'static str = "safe reports with problem dampener";
+
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        parse_reports(input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        parse_report_bytes(input)
+    }
+
+    fn compute(&self, reports: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(count_safe_reports(reports).into())
+    }
+}
+
+/// Dampened safe reports under a rule other than the puzzle's.
+pub struct PolicyDampenedSafeReports {
+    policy: SafetyPolicy,
+}
+
+impl PolicyDampenedSafeReports {
+    pub fn new(policy: SafetyPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl Solution for PolicyDampenedSafeReports {
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+    const NAME: &'static str = "safe reports with problem dampener (custom policy)";
+
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        parse_reports(input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        parse_report_bytes(input)
+    }
+
+    fn compute(&self, reports: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(count_safe_reports_under(reports, &self.policy).into())
+    }
+}
+
+pub fn find_num_safe_reports<R: BufRead>(reader: R) -> Result<i32, SolveError> {
+    let reports = parse_reports(reader)?;
+    Ok(count_safe_reports(&reports))
+}
+
+pub fn count_safe_reports(reports: &[Vec<i32>]) -> i32 {
+    count_safe_reports_under(reports, &SafetyPolicy::default())
+}
+
+pub fn count_safe_reports_under(reports: &[Vec<i32>], policy: &SafetyPolicy) -> i32 {
+    reports
+        .iter()
+        .map(|report| dampened_report_is_safe(report, policy))
+        .fold(0, sum_safe_reports)
+}
+
+pub fn determine_if_removing_any_one_element_makes_report_safe(report: &[i32]) -> bool {
+    dampened_report_is_safe(report, &SafetyPolicy::default())
+}
+
+/// Whether `report` is safe under `policy` once one of its levels is removed.
+/// Linear in the report's length; see `SafetyPolicy::fixing_removal`.
+pub fn dampened_report_is_safe(report: &[i32], policy: &SafetyPolicy) -> bool {
+    policy.fixing_removal(report).is_some()
+}
+
+pub fn report_is_safe(report: Vec<i32>) -> bool {
+    SafetyPolicy::default().is_safe(&report)
+}
+
+fn sum_safe_reports(accum: i32, is_safe: bool) -> i32 {
+    if is_safe {
+        accum + 1
+    } else {
+        accum
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_common::{
+    safety::{step, Trend},
+    SafetyPolicy,
+};
+
+/// How many reports need each number of removals to be safe, up to a limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovalHistogram {
+    /// `within[k]` is the number of reports that need exactly `k` removals.
+    pub within: Vec<usize>,
+    /// Reports that need more removals than the limit.
+    pub beyond: usize,
+}
+
+impl RemovalHistogram {
+    pub fn new(max_removals: usize) -> Self {
+        Self {
+            within: vec![0; max_removals + 1],
+            beyond: 0,
+        }
+    }
+
+    pub fn add(&mut self, removals: usize) {
+        match self.within.get_mut(removals) {
+            Some(count) => *count += 1,
+            None => self.beyond += 1,
+        }
+    }
+
+    /// Reports made safe by at most the limit's worth of removals.
+    pub fn safe(&self) -> usize {
+        self.within.iter().sum()
+    }
+}
+
+pub fn removal_histogram(
+    reports: &[Vec<i32>],
+    policy: &SafetyPolicy,
+    max_removals: usize,
+) -> RemovalHistogram {
+    let mut histogram = RemovalHistogram::new(max_removals);
+    for report in reports {
+        histogram.add(fewest_removals(report, policy).len());
+    }
+    histogram
+}
+
+/// The indices of the fewest levels to remove from `report` for it to be
+/// safe under `policy`, in increasing order.
+///
+/// These are the levels left out of the longest safe subsequence. For each
+/// trend, the longest chain of kept levels ending at each level is built from
+/// the best earlier level that can step to it, which takes O(n²). A drift
+/// limit depends on where a chain starts as well as where it ends, so then
+/// chains are built from each start separately, taking O(n³).
+///
+/// An empty report needs no removals, unlike part 2 where one level must
+/// always be removed.
+pub fn fewest_removals(report: &[i32], policy: &SafetyPolicy) -> Vec<usize> {
+    let starts: Vec<Option<usize>> = match policy.max_drift {
+        Some(_) => (0..report.len()).map(Some).collect(),
+        None => vec![None],
+    };
+
+    let mut kept = vec![false; report.len()];
+    let mut most_kept = 0;
+    for &trend in policy.trends() {
+        for &start in &starts {
+            let chain = longest_chain(report, policy, trend, start);
+            if chain.len() > most_kept {
+                most_kept = chain.len();
+                kept.fill(false);
+                for i in chain {
+                    kept[i] = true;
+                }
+            }
+        }
+    }
+
+    (0..report.len()).filter(|&i| !kept[i]).collect()
+}
+
+/// The indices of the longest chain of levels following `trend` whose steps
+/// `policy` allows, starting at `start` if given (and then also within its
+/// drift limit).
+fn longest_chain(
+    report: &[i32],
+    policy: &SafetyPolicy,
+    trend: Trend,
+    start: Option<usize>,
+) -> Vec<usize> {
+    // `length[j]` is the most levels in a chain ending at `j` (0 when no chain
+    // can), and `previous[j]` the level before `j` in it.
+    let mut length = vec![0; report.len()];
+    let mut previous = vec![None; report.len()];
+    for j in 0..report.len() {
+        if start.is_none_or(|s| s == j) {
+            length[j] = 1;
+        }
+        for i in 0..j {
+            if length[i] > 0
+                && length[i] + 1 > length[j]
+                && policy.allows_step(step(report[i], report[j]), trend)
+            {
+                length[j] = length[i] + 1;
+                previous[j] = Some(i);
+            }
+        }
+    }
+
+    let end = (0..report.len())
+        .filter(|&j| length[j] > 0)
+        .filter(|&j| start.is_none_or(|s| policy.within_drift(&[report[s], report[j]])))
+        .max_by_key(|&j| length[j]);
+    let mut chain: Vec<usize> = std::iter::successors(end, |&j| previous[j]).collect();
+    chain.reverse();
+    chain
+}
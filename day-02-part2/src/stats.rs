@@ -0,0 +1,103 @@
+use std::fmt;
+
+use aoc_common::{safety::step, SafetyPolicy};
+
+use crate::dampened_report_is_safe;
+
+/// Summary of one report, for loading into other tools.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportStats {
+    pub length: usize,
+    pub shape: Shape,
+    /// The smallest, largest and mean size of a step, ignoring which way it
+    /// goes. `None` for reports with fewer than two levels.
+    pub min_step: Option<u64>,
+    pub max_step: Option<u64>,
+    pub mean_step: Option<f64>,
+    /// How many steps break the policy, for whichever allowed trend the
+    /// report breaks least, plus one if it drifts too far.
+    pub violations: usize,
+    /// Safe under the part 1 rule.
+    pub safe: bool,
+    /// Safe under the part 2 rule.
+    pub dampened_safe: bool,
+    /// The level the Problem Dampener removes, for reports that are only safe
+    /// under the part 2 rule.
+    pub dampened_index: Option<usize>,
+}
+
+/// Which way a report's levels move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// Fewer than two levels.
+    None,
+    Increasing,
+    Decreasing,
+    Flat,
+    NonDecreasing,
+    NonIncreasing,
+    Mixed,
+}
+
+pub fn report_stats(report: &[i32], policy: &SafetyPolicy) -> ReportStats {
+    let steps: Vec<i64> = report.windows(2).map(|w| step(w[0], w[1])).collect();
+    let sizes = steps.iter().map(|s| s.unsigned_abs());
+    let violations = policy
+        .trends()
+        .iter()
+        .map(|&trend| {
+            steps
+                .iter()
+                .filter(|&&s| !policy.allows_step(s, trend))
+                .count()
+        })
+        .min()
+        .unwrap_or(0)
+        + usize::from(!policy.within_drift(report));
+    let safe = policy.is_safe(report);
+
+    ReportStats {
+        length: report.len(),
+        shape: shape(&steps),
+        min_step: sizes.clone().min(),
+        max_step: sizes.clone().max(),
+        mean_step: (!steps.is_empty())
+            .then(|| sizes.map(|s| s as f64).sum::<f64>() / steps.len() as f64),
+        violations,
+        safe,
+        dampened_safe: dampened_report_is_safe(report, policy),
+        dampened_index: if safe {
+            None
+        } else {
+            policy.fixing_removal(report)
+        },
+    }
+}
+
+fn shape(steps: &[i64]) -> Shape {
+    let (up, down) = (steps.iter().any(|&s| s > 0), steps.iter().any(|&s| s < 0));
+    let flat = steps.contains(&0);
+    match (up, down, flat) {
+        (true, true, _) => Shape::Mixed,
+        (true, false, false) => Shape::Increasing,
+        (true, false, true) => Shape::NonDecreasing,
+        (false, true, false) => Shape::Decreasing,
+        (false, true, true) => Shape::NonIncreasing,
+        (false, false, true) => Shape::Flat,
+        (false, false, false) => Shape::None,
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Shape::None => "none",
+            Shape::Increasing => "increasing",
+            Shape::Decreasing => "decreasing",
+            Shape::Flat => "flat",
+            Shape::NonDecreasing => "non-decreasing",
+            Shape::NonIncreasing => "non-increasing",
+            Shape::Mixed => "mixed",
+        })
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::BufRead;
+
+use aoc_common::scan::{add_product, consume_token, read_digits, scan_for_next_token_and_consume};
+use aoc_common::{input, numbered_lines, Answer, Line, Solution, SolveError};
+
+fn consume_open_paren(remaining: &str) -> Result<&str, &str> {
+    consume_token("(", remaining)
+}
+
+fn consume_comma(input: (i32, &str)) -> Result<(i32, &str), &str> {
+    let (multiplicand, remaining) = input;
+
+    consume_token(",", remaining).map(|r| (multiplicand, r))
+}
+
+fn read_second_number(input: (i32, &str)) -> Result<((i32, i32), &str), &str> {
+    let (multiplicand, remaining) = input;
+    read_digits(remaining).map(|(multiplier, r)| ((multiplicand, multiplier), r))
+}
+
+fn consume_close_paren(input: ((i32, i32), &str)) -> Result<((i32, i32), &str), &str> {
+    let (multiplication, remaining) = input;
+    consume_token(")", remaining).map(|r| (multiplication, r))
+}
+
+/// The sum of every `mul(a,b)` in `input`; see [`add_product`].
+pub fn sum_multiplications(input: &str) -> Result<i64, SolveError> {
+    let mut remaining = input;
+    let mut result = 0;
+    while !remaining.is_empty() {
+        let parse_result = scan_for_next_token_and_consume("mul", remaining)
+            .and_then(consume_open_paren)
+            .and_then(read_digits)
+            .and_then(consume_comma)
+            .and_then(read_second_number)
+            .and_then(consume_close_paren);
+
+        remaining = match parse_result {
+            Ok(((multiplicand, multiplier), new_remaining)) => {
+                result = add_product(result, multiplicand, multiplier, Multiplications::NAME)?;
+                new_remaining
+            }
+            Err(new_remaining) => new_remaining,
+        };
+    }
+
+    Ok(result)
+}
+
+pub struct Multiplications;
+
+impl Solution for Multiplications {
+    const DAY: u8 = 3;
+    const PART: u8 = 1;
+    const NAME: &'static str = "sum of multiplications";
+
+    type Parsed = input::WholeText;
+
+    fn parse<R: BufRead>(&self, mut input: R) -> Result<Self::Parsed, SolveError> {
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+        self.parse_bytes(&bytes)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        input::text(input).map(str::to_owned)
+    }
+
+    fn compute(&self, text: &Self::Parsed) -> Result<Answer, SolveError> {
+        sum_multiplications_in_text(text).map(Answer::from)
+    }
+
+    fn solve_bytes(&self, input: &[u8]) -> Result<Answer, SolveError> {
+        sum_multiplications_in_text(input::text(input)?).map(Answer::from)
+    }
+}
+
+pub fn sum_all_multiplications<R: BufRead>(reader: R) -> Result<i64, SolveError> {
+    let lines: Vec<Line> = numbered_lines(reader).collect::<Result<_, _>>()?;
+    sum_multiplications_in_lines(&lines)
+}
+
+pub fn sum_multiplications_in_lines(lines: &[Line]) -> Result<i64, SolveError> {
+    sum_multiplications_over(lines.iter().map(|line| (line.number, line.text.as_str())))
+}
+
+/// Like `sum_multiplications_in_lines`, for input already in memory.
+pub fn sum_multiplications_in_text(text: &str) -> Result<i64, SolveError> {
+    sum_multiplications_over(text.lines().enumerate().map(|(i, line)| (i + 1, line)))
+}
+
+fn sum_multiplications_over<'a>(
+    lines: impl Iterator<Item = (usize, &'a str)>,
+) -> Result<i64, SolveError> {
+    let mut result: i64 = 0;
+    for (number, text) in lines {
+        result = sum_multiplications(text)
+            .and_then(|line_result| {
+                result
+                    .checked_add(line_result)
+                    .ok_or_else(|| SolveError::overflow("sum of multiplications"))
+            })
+            .map_err(|e| e.at(number, 1, ""))?;
+    }
+
+    Ok(result)
+}
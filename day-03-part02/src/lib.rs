@@ -0,0 +1,170 @@
+use std::io::BufRead;
+
+use aoc_common::scan::{add_product, consume_token, read_digits};
+use aoc_common::{input, numbered_lines, Answer, Line, Solution, SolveError};
+
+enum Instruction {
+    Mul,
+    Do,
+    Dont,
+}
+impl From<&str> for Instruction {
+    fn from(value: &str) -> Self {
+        match value {
+            "mul" => Instruction::Mul,
+            "do()" => Instruction::Do,
+            "don't()" => Instruction::Dont,
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn scan_for_next_instruction(input: &str) -> Result<(Instruction, &str), &str> {
+    let instructions = ["mul", "do()", "don't()"];
+    let mut remaining = input;
+    while !input.is_empty() {
+        for i in instructions {
+            if remaining.starts_with(i) {
+                let (_, new_remaining) = remaining.split_at(i.len());
+                return Ok((i.into(), new_remaining));
+            }
+        }
+
+        if remaining.is_empty() {
+            return Err("");
+        }
+
+        let mut chars = remaining.chars();
+        chars.next();
+        remaining = chars.as_str();
+    }
+
+    Err("")
+}
+
+fn consume_open_paren(remaining: &str) -> Result<&str, &str> {
+    consume_token("(", remaining)
+}
+
+fn consume_comma(input: (i32, &str)) -> Result<(i32, &str), &str> {
+    let (multiplicand, remaining) = input;
+
+    consume_token(",", remaining).map(|r| (multiplicand, r))
+}
+
+fn read_second_number(input: (i32, &str)) -> Result<((i32, i32), &str), &str> {
+    let (multiplicand, remaining) = input;
+    read_digits(remaining).map(|(multiplier, r)| ((multiplicand, multiplier), r))
+}
+
+fn consume_close_paren(input: ((i32, i32), &str)) -> Result<((i32, i32), &str), &str> {
+    let (multiplication, remaining) = input;
+    consume_token(")", remaining).map(|r| (multiplication, r))
+}
+
+fn handle_mult_instruction(input: &str) -> Result<((i32, i32), &str), &str> {
+    consume_open_paren(input)
+        .and_then(read_digits)
+        .and_then(consume_comma)
+        .and_then(read_second_number)
+        .and_then(consume_close_paren)
+}
+
+/// The sum of the enabled `mul(a,b)`s in `input`, and whether they are still
+/// enabled at its end; see [`add_product`].
+pub fn sum_multiplications(mut enabled: bool, input: &str) -> Result<(bool, i64), SolveError> {
+    let mut remaining = input;
+    let mut result: i64 = 0;
+    while !remaining.is_empty() {
+        let ((multiplicand, multiplier), new_remaining) = scan_for_next_instruction(remaining)
+            .and_then(|(ins, r)| match ins {
+                Instruction::Mul => handle_mult_instruction(r),
+                Instruction::Do => {
+                    enabled = true;
+                    Ok(((0, 0), r))
+                }
+                Instruction::Dont => {
+                    enabled = false;
+                    Ok(((0, 0), r))
+                }
+            })
+            .unwrap_or_else(|r| ((0, 0), r));
+
+        if enabled {
+            result = add_product(
+                result,
+                multiplicand,
+                multiplier,
+                ConditionalMultiplications::NAME,
+            )?;
+        }
+        remaining = new_remaining;
+    }
+
+    Ok((enabled, result))
+}
+
+pub struct ConditionalMultiplications;
+
+impl Solution for ConditionalMultiplications {
+    const DAY: u8 = 3;
+    const PART: u8 = 2;
+    const NAME: &'static str = "sum of enabled multiplications";
+
+    type Parsed = input::WholeText;
+
+    fn parse<R: BufRead>(&self, mut input: R) -> Result<Self::Parsed, SolveError> {
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+        self.parse_bytes(&bytes)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        input::text(input).map(str::to_owned)
+    }
+
+    fn compute(&self, text: &Self::Parsed) -> Result<Answer, SolveError> {
+        sum_multiplications_in_text(text).map(Answer::from)
+    }
+
+    fn solve_bytes(&self, input: &[u8]) -> Result<Answer, SolveError> {
+        sum_multiplications_in_text(input::text(input)?).map(Answer::from)
+    }
+}
+
+pub fn sum_all_multiplications<R: BufRead>(reader: R) -> Result<i64, SolveError> {
+    let lines: Vec<Line> = numbered_lines(reader).collect::<Result<_, _>>()?;
+    sum_multiplications_in_lines(&lines)
+}
+
+pub fn sum_multiplications_in_lines(lines: &[Line]) -> Result<i64, SolveError> {
+    sum_multiplications_over(lines.iter().map(|line| (line.number, line.text.as_str())))
+}
+
+/// Like `sum_multiplications_in_lines`, for input already in memory.
+pub fn sum_multiplications_in_text(text: &str) -> Result<i64, SolveError> {
+    sum_multiplications_over(text.lines().enumerate().map(|(i, line)| (i + 1, line)))
+}
+
+fn sum_multiplications_over<'a>(
+    lines: impl Iterator<Item = (usize, &'a str)>,
+) -> Result<i64, SolveError> {
+    let mut result: i64 = 0;
+    let mut enabled = true;
+    for (number, text) in lines {
+        (enabled, result) = sum_multiplications(enabled, text)
+            .and_then(|(enabled, line_result)| {
+                Ok((
+                    enabled,
+                    result.checked_add(line_result).ok_or_else(overflow)?,
+                ))
+            })
+            .map_err(|e| e.at(number, 1, ""))?;
+    }
+
+    Ok(result)
+}
+
+fn overflow() -> SolveError {
+    SolveError::overflow("sum of enabled multiplications")
+}
@@ -0,0 +1,105 @@
+use std::io::BufRead;
+
+use aoc_common::grid::{
+    build_puzzle_map, build_puzzle_map_from_bytes, get_all_coordinates, letter_at, new_position,
+};
+use aoc_common::{Answer, Solution, SolveError};
+
+pub struct XmasSearch;
+
+impl Solution for XmasSearch {
+    const DAY: u8 = 4;
+    const PART: u8 = 1;
+    const NAME: &'static str = "XMAS occurrences";
+
+    type Parsed = Vec<Vec<char>>;
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        build_puzzle_map(input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        build_puzzle_map_from_bytes(input)
+    }
+
+    fn compute(&self, puzzle: &Self::Parsed) -> Result<Answer, SolveError> {
+        let (xmas_count, _) = find_xmas(puzzle);
+        Ok(xmas_count.into())
+    }
+}
+
+pub fn find_xmas(puzzle: &[Vec<char>]) -> (usize, Vec<(usize, usize)>) {
+    let mut word_coords = Vec::new();
+
+    let xmas_count: usize = get_all_coordinates(puzzle)
+        .filter(|(x, y)| letter_at(puzzle, *x, *y) == 'X')
+        .map(|(x, y)| {
+            let (num_found, coords) = search_from_coord(puzzle, x, y);
+
+            word_coords.extend(coords);
+            num_found
+        })
+        .sum();
+
+    (xmas_count, word_coords)
+}
+
+static NEXT_CHAR: &[(char, char)] = &[('A', 'S'), ('M', 'A'), ('X', 'M')];
+
+pub fn get_next_char(c: char) -> Option<char> {
+    NEXT_CHAR
+        .binary_search_by(|(k, _)| k.cmp(&c))
+        .map(|x| NEXT_CHAR[x].1)
+        .ok()
+}
+
+pub fn search_from_coord(puzzle: &[Vec<char>], x: usize, y: usize) -> (usize, Vec<(usize, usize)>) {
+    let paths: [[(i32, i32); 3]; 8] = [
+        [(-1, -1), (-2, -2), (-3, -3)], // UL
+        [(0, -1), (0, -2), (0, -3)],    //U
+        [(1, -1), (2, -2), (3, -3)],    // UR
+        [(-1, 0), (-2, 0), (-3, 0)],    // L
+        [(1, 0), (2, 0), (3, 0)],       // R
+        [(-1, 1), (-2, 2), (-3, 3)],    //DL
+        [(0, 1), (0, 2), (0, 3)],       // D
+        [(1, 1), (2, 2), (3, 3)],       // DR
+    ];
+
+    let mut word_coords: Vec<(usize, usize)> = Vec::new();
+
+    let num_found_xmas = paths
+        .iter()
+        .map(|p| get_next_three_coords(puzzle, x, y, p))
+        .filter(|p| p.is_ok())
+        .filter(|p| spells_xmas(puzzle, p.unwrap()))
+        .map(|p| {
+            word_coords.push((x, y));
+            word_coords.extend_from_slice(p.unwrap().as_slice());
+
+            1
+        })
+        .sum();
+
+    (num_found_xmas, word_coords)
+}
+
+fn get_next_three_coords(
+    puzzle: &[Vec<char>],
+    x: usize,
+    y: usize,
+    path: &[(i32, i32); 3],
+) -> Result<[(usize, usize); 3], ()> {
+    Ok([
+        new_position(puzzle, x, y, path[0].0, path[0].1).ok_or(())?,
+        new_position(puzzle, x, y, path[1].0, path[1].1).ok_or(())?,
+        new_position(puzzle, x, y, path[2].0, path[2].1).ok_or(())?,
+    ])
+}
+
+fn spells_xmas(puzzle: &[Vec<char>], p: [(usize, usize); 3]) -> bool {
+    let maybe_m = letter_at(puzzle, p[0].0, p[0].1);
+    let maybe_a = letter_at(puzzle, p[1].0, p[1].1);
+    let maybe_s = letter_at(puzzle, p[2].0, p[2].1);
+
+    maybe_m == 'M' && maybe_a == 'A' && maybe_s == 'S'
+}
@@ -0,0 +1,97 @@
+use std::io::BufRead;
+
+use aoc_common::grid::{
+    build_puzzle_map, build_puzzle_map_from_bytes, get_all_coordinates, letter_at, new_position,
+};
+use aoc_common::{Answer, Solution, SolveError};
+
+pub struct CrossMasSearch;
+
+impl Solution for CrossMasSearch {
+    const DAY: u8 = 4;
+    const PART: u8 = 2;
+    const NAME: &'static str = "X-MAS occurrences";
+
+    type Parsed = Vec<Vec<char>>;
+
+    fn parse<R: BufRead>(&self, input: R) -> Result<Self::Parsed, SolveError> {
+        build_puzzle_map(input)
+    }
+
+    fn parse_bytes(&self, input: &[u8]) -> Result<Self::Parsed, SolveError> {
+        build_puzzle_map_from_bytes(input)
+    }
+
+    fn compute(&self, puzzle: &Self::Parsed) -> Result<Answer, SolveError> {
+        let (xmas_count, _) = find_xmas(puzzle);
+        Ok(xmas_count.into())
+    }
+}
+
+pub fn find_xmas(puzzle: &[Vec<char>]) -> (usize, Vec<(usize, usize)>) {
+    let mut word_coords = Vec::new();
+
+    let xmas_count: usize = get_all_coordinates(puzzle)
+        .filter(|(x, y)| letter_at(puzzle, *x, *y) == 'A')
+        .filter(|(x, y)| search_from_coord(puzzle, *x, *y, &mut word_coords))
+        .map(|_| 1)
+        .sum();
+
+    (xmas_count, word_coords)
+}
+
+pub fn search_from_coord(
+    puzzle: &[Vec<char>],
+    x: usize,
+    y: usize,
+    coords_in_xmas: &mut Vec<(usize, usize)>,
+) -> bool {
+    let paths: [[(i32, i32); 2]; 2] = [
+        [(-1, -1), (1, 1)], // UL, BR
+        [(-1, 1), (1, -1)], // UR, BL
+    ];
+
+    let bounds_checked_paths = [
+        get_next_two_coords(puzzle, x, y, &paths[0]),
+        get_next_two_coords(puzzle, x, y, &paths[1]),
+    ];
+    if bounds_checked_paths.iter().any(|p| p.is_err()) {
+        return false;
+    }
+    let bounds_checked_paths = [
+        bounds_checked_paths[0].unwrap(),
+        bounds_checked_paths[1].unwrap(),
+    ];
+
+    if bounds_checked_paths.iter().any(|p| !spells_mas(puzzle, *p)) {
+        return false;
+    }
+
+    coords_in_xmas.push((x, y));
+    coords_in_xmas.push(bounds_checked_paths[0][0]);
+    coords_in_xmas.push(bounds_checked_paths[0][1]);
+    coords_in_xmas.push(bounds_checked_paths[1][0]);
+    coords_in_xmas.push(bounds_checked_paths[1][1]);
+
+    true
+}
+
+fn get_next_two_coords(
+    puzzle: &[Vec<char>],
+    x: usize,
+    y: usize,
+    path: &[(i32, i32); 2],
+) -> Result<[(usize, usize); 2], ()> {
+    Ok([
+        new_position(puzzle, x, y, path[0].0, path[0].1).ok_or(())?,
+        new_position(puzzle, x, y, path[1].0, path[1].1).ok_or(())?,
+    ])
+}
+
+fn spells_mas(puzzle: &[Vec<char>], path: [(usize, usize); 2]) -> bool {
+    let letter = [
+        letter_at(puzzle, path[0].0, path[0].1),
+        letter_at(puzzle, path[1].0, path[1].1),
+    ];
+    letter == ['M', 'S'] || letter == ['S', 'M']
+}